@@ -0,0 +1,8 @@
+/// Copies of what the daemon reported, kept to save requests, which have to be forgotten once the
+/// objects change.
+pub trait Cache: Send + Sync {
+    /// Forgets what is kept about the object with the ID or name.
+    fn invalidate(&self, key: &str);
+    /// Forgets everything.
+    fn clear(&self);
+}
@@ -1,34 +1,187 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 use std::error::Error;
+use std::fmt;
 
 #[async_trait]
 pub trait ContainerRepository {
     async fn list(&self) -> Result<Vec<ContainerSummary>, Box<dyn Error + Send + Sync>>;
+    /// Containers, running or not, which mount the named volume.
+    async fn list_by_volume(&self, volume: String) -> Result<Vec<ContainerSummary>, Box<dyn Error + Send + Sync>>;
+    async fn inspect(&self, id: String) -> Result<Container, Box<dyn Error + Send + Sync>>;
+    async fn start(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn stop(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn restart(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn kill(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn pause(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn unpause(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>>;
 }
 
 pub struct ContainerSummary {
     pub id: String,
     pub names: Vec<String>,
     pub image: String,
+    pub command: String,
     pub created: DateTime<Utc>,
+    pub state: String,
     pub status: String,
+    pub ports: Vec<Port>,
+    pub labels: HashMap<String, String>,
 }
 
 impl ContainerSummary {
+    #[allow(clippy::too_many_arguments)]
     pub fn from_repository(
         id: String,
         names: Vec<String>,
         image: String,
+        command: String,
         created: DateTime<Utc>,
+        state: String,
         status: String,
+        ports: Vec<Port>,
+        labels: HashMap<String, String>,
     ) -> Self {
         Self {
             id,
             names,
             image,
+            command,
             created,
+            state,
             status,
+            ports,
+            labels,
         }
     }
+
+    /// Returns the primary name without the leading slash the daemon prepends.
+    pub fn name(&self) -> &str {
+        self.names.first().map_or("", |n| n.trim_start_matches('/'))
+    }
+
+    /// Takes the state of the container inspected again, e.g. after an action. The status reads like the
+    /// daemon's without how long the container has been in the state, until the list is fetched again.
+    pub fn update_state(&mut self, state: &ContainerState) {
+        self.state = state.status.clone();
+        self.status = match state.status.as_str() {
+            "running" if state.paused => "Up (Paused)".to_string(),
+            "running" => "Up".to_string(),
+            "paused" => "Up (Paused)".to_string(),
+            "restarting" => format!("Restarting ({})", state.exit_code),
+            "exited" => format!("Exited ({})", state.exit_code),
+            status => {
+                let mut chars = status.chars();
+                chars
+                    .next()
+                    .map_or(String::new(), |c| c.to_uppercase().chain(chars).collect())
+            }
+        };
+    }
+}
+
+pub struct Port {
+    pub ip: Option<String>,
+    pub private_port: u16,
+    pub public_port: Option<u16>,
+    pub protocol: String,
+}
+
+impl fmt::Display for Port {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.ip, self.public_port) {
+            (Some(ip), Some(public)) => write!(f, "{}:{}->{}/{}", ip, public, self.private_port, self.protocol),
+            (None, Some(public)) => write!(f, "{}->{}/{}", public, self.private_port, self.protocol),
+            _ => write!(f, "{}/{}", self.private_port, self.protocol),
+        }
+    }
+}
+
+pub struct Container {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub created: DateTime<Utc>,
+    pub state: ContainerState,
+    pub config: ContainerConfig,
+    pub host_config: HostConfig,
+    pub mounts: Vec<Mount>,
+    pub network_settings: NetworkSettings,
+}
+
+pub struct ContainerState {
+    pub status: String,
+    pub running: bool,
+    pub paused: bool,
+    pub restarting: bool,
+    pub oom_killed: bool,
+    pub dead: bool,
+    pub pid: i64,
+    pub exit_code: i64,
+    pub error: String,
+    pub started_at: String,
+    pub finished_at: String,
+    pub health: Option<Health>,
+}
+
+pub struct Health {
+    pub status: String,
+    pub failing_streak: i64,
+    pub log: Vec<HealthCheckResult>,
+}
+
+pub struct HealthCheckResult {
+    pub start: String,
+    pub end: String,
+    pub exit_code: i64,
+    pub output: String,
+}
+
+pub struct ContainerConfig {
+    pub hostname: String,
+    pub user: String,
+    pub env: Vec<String>,
+    pub cmd: Vec<String>,
+    pub entrypoint: Vec<String>,
+    pub working_dir: String,
+    pub labels: HashMap<String, String>,
+    pub tty: bool,
+    pub open_stdin: bool,
+    pub exposed_ports: Vec<String>,
+}
+
+pub struct HostConfig {
+    pub network_mode: String,
+    pub privileged: bool,
+    pub binds: Vec<String>,
+    pub restart_policy: RestartPolicy,
+}
+
+pub struct RestartPolicy {
+    pub name: String,
+    pub maximum_retry_count: i64,
+}
+
+pub struct Mount {
+    pub mount_type: String,
+    pub name: Option<String>,
+    pub source: String,
+    pub destination: String,
+    pub driver: Option<String>,
+    pub mode: String,
+    pub rw: bool,
+}
+
+pub struct NetworkSettings {
+    pub ports: Vec<Port>,
+    pub networks: HashMap<String, EndpointSettings>,
+}
+
+pub struct EndpointSettings {
+    pub network_id: String,
+    pub ip_address: String,
+    pub gateway: String,
+    pub mac_address: String,
+    pub aliases: Vec<String>,
 }
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use std::error::Error;
+
+/// Name of the implicit context which uses `DOCKER_HOST` or the local socket.
+pub const DEFAULT_CONTEXT: &str = "default";
+
+#[async_trait]
+pub trait ContextRepository {
+    /// Lists the default context followed by every context created with `docker context create`.
+    async fn list(&self) -> Result<Vec<Context>, Box<dyn Error + Send + Sync>>;
+}
+
+pub struct Context {
+    pub name: String,
+    pub description: String,
+    /// Docker endpoint, e.g. `unix:///var/run/docker.sock` or `tcp://10.0.0.1:2376`.
+    pub host: String,
+    /// Whether the context is the one selected with `docker context use`.
+    pub current: bool,
+}
@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt;
+
+/// What kind of failure a repository ran into, for callers which handle some of them differently, e.g.
+/// offering to force a removal the daemon refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// e.g. the container was removed in the meantime.
+    NotFound,
+    /// e.g. removing an image a container still uses.
+    Conflict,
+    Unauthorized,
+    /// The daemon could not be reached.
+    Unavailable,
+    Other,
+}
+
+impl ErrorKind {
+    /// The kind of an error a repository returned, `Other` unless the repository classified it.
+    pub fn of(error: &(dyn Error + 'static)) -> ErrorKind {
+        error
+            .downcast_ref::<RepositoryError>()
+            .map_or(ErrorKind::Other, RepositoryError::kind)
+    }
+}
+
+/// An error of a repository together with its kind. Repositories return it boxed like any other error,
+/// and it reads just like the error it wraps.
+#[derive(Debug)]
+pub struct RepositoryError {
+    kind: ErrorKind,
+    error: Box<dyn Error + Send + Sync>,
+}
+
+impl RepositoryError {
+    pub fn new(kind: ErrorKind, error: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            kind,
+            error: error.into(),
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for RepositoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl Error for RepositoryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
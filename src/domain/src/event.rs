@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_core::stream::Stream;
+use std::collections::HashMap;
+use std::error::Error;
+use std::pin::Pin;
+
+pub type EventStream = Pin<Box<dyn Stream<Item = Result<SystemEvent, Box<dyn Error + Send + Sync>>> + Send>>;
+
+#[async_trait]
+pub trait EventRepository {
+    /// Streams what happens on the daemon from now on, or replays from `since` first.
+    async fn events(&self, since: Option<DateTime<Utc>>) -> Result<EventStream, Box<dyn Error + Send + Sync>>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    Container,
+    Image,
+    Volume,
+    Network,
+    Daemon,
+    Other(String),
+}
+
+impl EventKind {
+    pub fn name(&self) -> &str {
+        match self {
+            EventKind::Container => "container",
+            EventKind::Image => "image",
+            EventKind::Volume => "volume",
+            EventKind::Network => "network",
+            EventKind::Daemon => "daemon",
+            EventKind::Other(name) => name,
+        }
+    }
+}
+
+/// Something which happened to an object of the daemon, e.g. a container dying or an image being pulled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SystemEvent {
+    pub kind: EventKind,
+    /// `start`, `die`, `oom`, `pull`, `delete`, `connect`, `mount`, ... Exec events carry their
+    /// command, e.g. `exec_start: sh`.
+    pub action: String,
+    /// ID of the object, or the name for volumes and images referenced by name.
+    pub actor_id: String,
+    pub attributes: HashMap<String, String>,
+    pub time: DateTime<Utc>,
+}
+
+impl SystemEvent {
+    /// The action without the details some actions carry after a colon.
+    pub fn verb(&self) -> &str {
+        self.action.split(':').next().unwrap_or_default().trim()
+    }
+
+    /// A readable name for the object, e.g. the container's name rather than its ID.
+    pub fn actor_name(&self) -> &str {
+        self.attributes
+            .get("name")
+            .map(|v| v.as_str())
+            .unwrap_or_else(|| self.actor_id.as_str())
+    }
+
+    /// Whether the object changed in a way lists show, as opposed to e.g. a shell being run in a
+    /// container or its logs being attached to.
+    pub fn alters_listing(&self) -> bool {
+        match self.kind {
+            EventKind::Container => {
+                !matches!(
+                    self.verb(),
+                    "attach" | "detach" | "copy" | "export" | "resize" | "top" | "commit"
+                ) && !self.verb().starts_with("exec_")
+            }
+            EventKind::Daemon | EventKind::Other(_) => false,
+            _ => true,
+        }
+    }
+}
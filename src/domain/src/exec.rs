@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use futures_core::stream::Stream;
+use std::error::Error;
+use std::pin::Pin;
+
+pub type ExecOutput = Pin<Box<dyn Stream<Item = Result<Vec<u8>, Box<dyn Error + Send + Sync>>> + Send>>;
+
+#[async_trait]
+pub trait ExecRepository {
+    /// Creates an interactive exec instance with a TTY and returns its id.
+    async fn create(&self, container_id: String, cmd: Vec<String>) -> Result<String, Box<dyn Error + Send + Sync>>;
+    async fn start(&self, exec_id: String) -> Result<ExecSession, Box<dyn Error + Send + Sync>>;
+    async fn resize(&self, exec_id: String, width: u16, height: u16) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+/// The stdin half of a running exec. Writes are queued and never block the caller.
+pub trait ExecInput: Send {
+    fn write(&mut self, bytes: Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+pub struct ExecSession {
+    pub id: String,
+    /// Raw TTY output of the process; it ends when the process exits.
+    pub output: ExecOutput,
+    pub input: Box<dyn ExecInput>,
+}
@@ -1,33 +1,58 @@
+use crate::size::ByteSize;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures_core::stream::Stream;
 use std::collections::HashMap;
 use std::error::Error;
+use std::pin::Pin;
+
+pub type PullStream = Pin<Box<dyn Stream<Item = Result<PullProgress, Box<dyn Error + Send + Sync>>> + Send>>;
 
 #[async_trait]
 pub trait ImageRepository {
     async fn list(&self) -> Result<Vec<ImageSummary>, Box<dyn Error + Send + Sync>>;
     async fn inspect(&self, id: String) -> Result<Image, Box<dyn Error + Send + Sync>>;
     async fn history(&self, id: String) -> Result<ImageHistory, Box<dyn Error + Send + Sync>>;
+    /// Pulls the image with a tag or digest, e.g. `nginx:latest`. The stream reports the progress until the
+    /// pull is done, and fails with the daemon's message when the pull does.
+    async fn pull(&self, reference: String) -> Result<PullStream, Box<dyn Error + Send + Sync>>;
+    /// Removes the image by ID or reference. Given a tag of an image which has other tags, only the tag
+    /// is removed. Without `force` it fails while containers use the image or it has several tags;
+    /// `no_prune` keeps untagged parent images.
+    async fn remove(
+        &self,
+        id: String,
+        force: bool,
+        no_prune: bool,
+    ) -> Result<ImageRemoveReport, Box<dyn Error + Send + Sync>>;
+    /// Adds the tag `repo:tag` to the image.
+    async fn tag(&self, id: String, repo: String, tag: String) -> Result<(), Box<dyn Error + Send + Sync>>;
 }
 
 pub struct ImageSummary {
     pub id: String,
+    /// Empty for untagged images.
     pub repo_tags: Vec<String>,
+    /// `repo@sha256:...` for each repository the image was pulled from or pushed to.
+    pub repo_digests: Vec<String>,
+    /// The image it was built from, `None` for pulled images.
+    pub parent_id: Option<String>,
     pub created: DateTime<Utc>,
-    pub size: i32,
+    pub size: ByteSize,
+    /// The part of `size` in layers other images use as well, when the daemon computed it.
+    pub shared_size: Option<ByteSize>,
+    /// Number of containers using the image, when the daemon computed it.
+    pub containers: Option<u64>,
 }
 
 impl ImageSummary {
-    pub fn from_repository(id: String, repo_tags: Vec<String>, created: DateTime<Utc>, size: i32) -> Self {
-        Self {
-            id,
-            repo_tags,
-            created,
-            size,
-        }
+    /// Neither tagged nor pulled by digest, usually left behind when a tag moved to a newer build.
+    pub fn is_dangling(&self) -> bool {
+        self.repo_tags.is_empty() && self.repo_digests.is_empty()
     }
 }
 
+#[derive(Clone)]
 pub struct Image {
     pub id: String,
     pub repo_tags: Vec<String>,
@@ -41,8 +66,28 @@ pub struct Image {
 
 pub type ImageHistory = Vec<ImageRecord>;
 
+#[derive(Clone)]
 pub struct ImageRecord {
     pub id: String,
     pub created_by: String,
-    pub size: i32,
+    pub size: ByteSize,
+}
+
+/// One progress message of a pull, either about one layer or about the whole image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PullProgress {
+    /// The layer the message is about, or e.g. the tag for `Pulling from library/nginx`.
+    pub id: Option<String>,
+    /// `Pulling fs layer`, `Downloading`, `Extracting`, `Pull complete`, `Digest: sha256:...`, ...
+    pub status: String,
+    /// Bytes done and in total while downloading or extracting a layer.
+    pub current: Option<u64>,
+    pub total: Option<u64>,
+}
+
+/// What a removal did: tags it removed and images it deleted, including untagged parents.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImageRemoveReport {
+    pub untagged: Vec<String>,
+    pub deleted: Vec<String>,
 }
@@ -1,2 +1,13 @@
+pub mod cache;
 pub mod container;
+pub mod context;
+pub mod error;
+pub mod event;
+pub mod exec;
 pub mod image;
+pub mod log;
+pub mod network;
+pub mod size;
+pub mod stats;
+pub mod system;
+pub mod volume;
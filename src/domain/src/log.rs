@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_core::stream::Stream;
+use std::error::Error;
+use std::pin::Pin;
+
+pub type LogStream = Pin<Box<dyn Stream<Item = Result<LogLine, Box<dyn Error + Send + Sync>>> + Send>>;
+
+#[async_trait]
+pub trait LogRepository {
+    async fn logs(&self, id: String, options: LogOptions) -> Result<LogStream, Box<dyn Error + Send + Sync>>;
+}
+
+pub struct LogOptions {
+    pub follow: bool,
+    pub timestamps: bool,
+    /// Number of lines to show from the end of the logs, or all of them when `None`.
+    pub tail: Option<u64>,
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl Default for LogOptions {
+    fn default() -> Self {
+        Self {
+            follow: true,
+            timestamps: true,
+            tail: Some(1000),
+            since: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogSource {
+    Stdout,
+    Stderr,
+}
+
+pub struct LogLine {
+    pub source: LogSource,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub message: String,
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::error::Error;
+
+#[async_trait]
+pub trait NetworkRepository {
+    /// Networks without their containers, which only `inspect` fills in.
+    async fn list(&self) -> Result<Vec<Network>, Box<dyn Error + Send + Sync>>;
+    async fn inspect(&self, id: String) -> Result<Network, Box<dyn Error + Send + Sync>>;
+    /// Returns the ID of the new network.
+    async fn create(&self, options: CreateNetworkOptions) -> Result<String, Box<dyn Error + Send + Sync>>;
+    async fn remove(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Removes every network no container is connected to.
+    async fn prune(&self) -> Result<NetworkPruneReport, Box<dyn Error + Send + Sync>>;
+    async fn connect(&self, id: String, container: String) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn disconnect(&self, id: String, container: String, force: bool) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+pub struct Network {
+    pub id: String,
+    pub name: String,
+    /// `bridge`, `host`, `overlay`, `macvlan`, `null`, ...
+    pub driver: String,
+    /// `local`, `global` or `swarm`.
+    pub scope: String,
+    pub created: Option<DateTime<Utc>>,
+    pub internal: bool,
+    pub attachable: bool,
+    pub ipam: Ipam,
+    /// Containers connected to the network by ID.
+    pub containers: HashMap<String, NetworkEndpoint>,
+    pub options: HashMap<String, String>,
+    pub labels: HashMap<String, String>,
+}
+
+pub struct Ipam {
+    pub driver: String,
+    pub config: Vec<IpamConfig>,
+}
+
+/// One address pool of the network. Every field may be empty, e.g. for `host` and `none`.
+pub struct IpamConfig {
+    pub subnet: String,
+    pub gateway: String,
+    pub ip_range: String,
+}
+
+pub struct NetworkEndpoint {
+    pub name: String,
+    /// In CIDR notation, e.g. `172.17.0.2/16`.
+    pub ipv4_address: String,
+    pub ipv6_address: String,
+    pub mac_address: String,
+}
+
+#[derive(Default)]
+pub struct CreateNetworkOptions {
+    pub name: String,
+    /// Defaults to `bridge` when empty.
+    pub driver: String,
+    pub internal: bool,
+    pub attachable: bool,
+    pub labels: HashMap<String, String>,
+}
+
+pub struct NetworkPruneReport {
+    pub networks_deleted: Vec<String>,
+}
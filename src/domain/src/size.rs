@@ -0,0 +1,98 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+
+/// A number of bytes, e.g. the size of an image, a volume or a container's memory.
+///
+/// It shows with decimal units as `docker system df` shows disk usage, e.g. `1.50GB`, and with binary units
+/// as `docker stats` shows memory when formatted with `{:#}`, e.g. `1.40GiB`. It has two decimals unless
+/// the format gives a precision, e.g. `{:.1}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSize(pub u64);
+
+const DECIMAL_UNITS: [&str; 6] = ["B", "KB", "MB", "GB", "TB", "PB"];
+const BINARY_UNITS: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+impl ByteSize {
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// For sizes the daemon reports as signed numbers, with -1 for sizes it didn't compute.
+    pub fn from_signed(bytes: i64) -> Option<Self> {
+        if bytes < 0 {
+            None
+        } else {
+            Some(ByteSize(bytes as u64))
+        }
+    }
+
+    pub fn saturating_sub(self, other: ByteSize) -> ByteSize {
+        ByteSize(self.0.saturating_sub(other.0))
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        ByteSize(bytes)
+    }
+}
+
+impl Add for ByteSize {
+    type Output = ByteSize;
+
+    fn add(self, other: ByteSize) -> ByteSize {
+        ByteSize(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sum for ByteSize {
+    fn sum<I: Iterator<Item = ByteSize>>(iter: I) -> Self {
+        iter.fold(ByteSize::default(), Add::add)
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (base, units) = if f.alternate() {
+            (1024.0, BINARY_UNITS)
+        } else {
+            (1000.0, DECIMAL_UNITS)
+        };
+        if (self.0 as f64) < base {
+            return write!(f, "{}B", self.0);
+        }
+        let mut value = self.0 as f64;
+        let mut unit = 0;
+        let precision = f.precision().unwrap_or(2);
+        let factor = 10f64.powi(precision as i32);
+        // Rounded to the precision, e.g. 999.999KB reaches the next unit as 1.00MB.
+        while (value * factor).round() / factor >= base && unit < units.len() - 1 {
+            value /= base;
+            unit += 1;
+        }
+        write!(f, "{:.*}{}", precision, value, units[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByteSize;
+
+    #[test]
+    fn formatting() {
+        assert_eq!(ByteSize(999).to_string(), "999B");
+        assert_eq!(ByteSize(1_500_000_000).to_string(), "1.50GB");
+        assert_eq!(format!("{:.1}", ByteSize(5_300_000_000)), "5.3GB");
+        assert_eq!(format!("{:#}", ByteSize(1_536)), "1.50KiB");
+        assert_eq!(format!("{:#.0}", ByteSize(3 << 30)), "3GiB");
+        assert_eq!(ByteSize::from_signed(-1), None);
+    }
+
+    #[test]
+    fn rounding_up_to_the_next_unit() {
+        assert_eq!(ByteSize(999_999).to_string(), "1.00MB");
+        assert_eq!(ByteSize(999_994).to_string(), "999.99KB");
+        assert_eq!(format!("{:.0}", ByteSize(999_500)), "1MB");
+    }
+}
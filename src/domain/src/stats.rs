@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_core::stream::Stream;
+use std::error::Error;
+use std::pin::Pin;
+
+pub type StatsStream = Pin<Box<dyn Stream<Item = Result<StatsSample, Box<dyn Error + Send + Sync>>> + Send>>;
+
+#[async_trait]
+pub trait StatsRepository {
+    /// Streams a sample roughly every second until the container stops or the stream is dropped.
+    async fn stats(&self, id: String) -> Result<StatsStream, Box<dyn Error + Send + Sync>>;
+}
+
+/// One reading of `/containers/{id}/stats`. Every counter is cumulative since the container started.
+pub struct StatsSample {
+    pub read: DateTime<Utc>,
+    pub cpu: CpuUsage,
+    /// The CPU reading the daemon took right before `cpu`.
+    pub precpu: CpuUsage,
+    pub memory_usage: u64,
+    /// Page cache included in `memory_usage` which the kernel can reclaim.
+    pub memory_cache: u64,
+    pub memory_limit: u64,
+    pub network_rx_bytes: u64,
+    pub network_tx_bytes: u64,
+    pub block_read_bytes: u64,
+    pub block_write_bytes: u64,
+    pub pids: u64,
+}
+
+pub struct CpuUsage {
+    pub total_usage: u64,
+    pub system_usage: u64,
+    pub online_cpus: u32,
+}
+
+impl StatsSample {
+    /// CPU usage in percent where each core counts for 100%, the same way as `docker stats`.
+    pub fn cpu_percent(&self) -> f64 {
+        let cpu_delta = self.cpu.total_usage.saturating_sub(self.precpu.total_usage);
+        let system_delta = self.cpu.system_usage.saturating_sub(self.precpu.system_usage);
+        if cpu_delta == 0 || system_delta == 0 {
+            return 0.0;
+        }
+        cpu_delta as f64 / system_delta as f64 * f64::from(self.cpu.online_cpus.max(1)) * 100.0
+    }
+
+    pub fn memory_used(&self) -> u64 {
+        self.memory_usage.saturating_sub(self.memory_cache)
+    }
+
+    pub fn memory_percent(&self) -> f64 {
+        if self.memory_limit == 0 {
+            return 0.0;
+        }
+        self.memory_used() as f64 / self.memory_limit as f64 * 100.0
+    }
+}
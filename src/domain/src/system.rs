@@ -0,0 +1,70 @@
+use crate::size::ByteSize;
+use crate::volume::VolumeUsage;
+use async_trait::async_trait;
+use std::error::Error;
+
+#[async_trait]
+pub trait SystemRepository {
+    /// What images, containers, volumes and the build cache take on disk, like `docker system df`.
+    async fn disk_usage(&self) -> Result<DiskUsage, Box<dyn Error + Send + Sync>>;
+    /// Removes the unused objects of the given kind.
+    async fn prune(
+        &self,
+        target: PruneTarget,
+        options: PruneOptions,
+    ) -> Result<PruneReport, Box<dyn Error + Send + Sync>>;
+}
+
+/// The objects the daemon reports. Sizes it couldn't compute are `None`.
+pub struct DiskUsage {
+    /// Disk space of every image layer, counting layers shared between images once.
+    pub layers_size: ByteSize,
+    pub images: Vec<ImageUsage>,
+    pub containers: Vec<ContainerUsage>,
+    pub volumes: Vec<VolumeUsage>,
+    pub build_cache: Vec<BuildCacheUsage>,
+}
+
+pub struct ImageUsage {
+    pub size: ByteSize,
+    /// The part of `size` in layers other images use as well.
+    pub shared_size: Option<ByteSize>,
+    /// Number of containers using the image.
+    pub containers: Option<u64>,
+}
+
+pub struct ContainerUsage {
+    /// Disk space of the files the container wrote.
+    pub size_rw: Option<ByteSize>,
+    pub running: bool,
+}
+
+pub struct BuildCacheUsage {
+    pub size: ByteSize,
+    pub in_use: bool,
+    /// Shared records can't be pruned on their own.
+    pub shared: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneTarget {
+    Images,
+    Containers,
+    Volumes,
+    BuildCache,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PruneOptions {
+    /// Prunes unused tagged images rather than only dangling ones, named volumes rather than only
+    /// anonymous ones, and every unused build cache record rather than only the internal ones.
+    pub all: bool,
+    /// Only prunes objects created before the timestamp or duration, e.g. `24h`. Volumes don't support it.
+    pub until: Option<String>,
+}
+
+pub struct PruneReport {
+    /// IDs, or names for volumes, of the removed objects.
+    pub deleted: Vec<String>,
+    pub space_reclaimed: ByteSize,
+}
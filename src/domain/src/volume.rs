@@ -0,0 +1,53 @@
+use crate::size::ByteSize;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::error::Error;
+
+#[async_trait]
+pub trait VolumeRepository {
+    async fn list(&self) -> Result<Vec<Volume>, Box<dyn Error + Send + Sync>>;
+    async fn inspect(&self, name: String) -> Result<Volume, Box<dyn Error + Send + Sync>>;
+    async fn create(&self, options: CreateVolumeOptions) -> Result<Volume, Box<dyn Error + Send + Sync>>;
+    /// Fails while a container uses the volume unless `force` is set.
+    async fn remove(&self, name: String, force: bool) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Removes every volume no container uses.
+    async fn prune(&self) -> Result<VolumePruneReport, Box<dyn Error + Send + Sync>>;
+}
+
+pub struct Volume {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+    pub created: Option<DateTime<Utc>>,
+    /// `local` or `global`.
+    pub scope: String,
+    pub labels: HashMap<String, String>,
+    pub options: HashMap<String, String>,
+    /// Only known for drivers which report it, and only when it was asked for as computing it is costly:
+    /// the list has it, a single volume doesn't.
+    pub usage: Option<VolumeUsage>,
+}
+
+#[derive(Clone, Copy)]
+pub struct VolumeUsage {
+    /// Disk space the volume uses, `None` when the driver can't tell.
+    pub size: Option<ByteSize>,
+    /// Number of containers referencing the volume.
+    pub ref_count: u64,
+}
+
+#[derive(Default)]
+pub struct CreateVolumeOptions {
+    /// The daemon generates a name when it is empty.
+    pub name: String,
+    /// Defaults to `local` when empty.
+    pub driver: String,
+    pub driver_opts: HashMap<String, String>,
+    pub labels: HashMap<String, String>,
+}
+
+pub struct VolumePruneReport {
+    pub volumes_deleted: Vec<String>,
+    pub space_reclaimed: ByteSize,
+}
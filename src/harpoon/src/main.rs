@@ -6,21 +6,31 @@ use std::error::Error;
 #[derive(Clap)]
 #[clap(version = "0.1.0", author = "Kenji S. <xxxxxxxxxx@gmail.com>")]
 struct Opts {
-    #[clap(short, long, default_value = "1.39")]
-    api_version: String,
+    /// Defaults to $DOCKER_API_VERSION, then 1.39
+    #[clap(short, long)]
+    api_version: Option<String>,
 
-    #[clap(short, long, default_value = "unix:///var/run/docker.sock")]
-    endpoint: String,
+    /// Defaults to $DOCKER_HOST, then unix:///var/run/docker.sock
+    #[clap(short, long)]
+    endpoint: Option<String>,
+
+    /// Docker context to use, defaults to $DOCKER_CONTEXT, then the current context
+    #[clap(short, long)]
+    context: Option<String>,
 
     #[clap(short, long, default_value = "0")]
     verbose: i32,
 }
 
 #[tokio::main]
-#[allow(unused_variables)]
 async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     let opts: Opts = Opts::parse();
 
-    init::draw_by_default().await?;
+    init::draw_by_default(
+        opts.endpoint.as_deref(),
+        opts.api_version.as_deref(),
+        opts.context.as_deref(),
+    )
+    .await?;
     Ok(())
 }
@@ -0,0 +1,204 @@
+use crate::webapi::rest::client::{Endpoint, TlsConfig, DEFAULT_ENDPOINT};
+use async_trait::async_trait;
+use domain::context::{Context, ContextRepository, DEFAULT_CONTEXT};
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Reads the contexts the docker CLI keeps in its config directory, usually `~/.docker`:
+/// `config.json` names the current context, `contexts/meta/<id>/meta.json` describes each context
+/// and `contexts/tls/<id>/docker` holds its certificates.
+pub struct FileContextRepository {
+    config_dir: PathBuf,
+    /// Host of the default context, which isn't stored on disk.
+    default_host: String,
+}
+
+/// How to reach the daemon of a stored context.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextEndpoint {
+    pub endpoint: Endpoint,
+    pub tls: Option<TlsConfig>,
+}
+
+impl FileContextRepository {
+    pub fn new(config_dir: impl Into<PathBuf>, default_host: impl Into<String>) -> Self {
+        Self {
+            config_dir: config_dir.into(),
+            default_host: default_host.into(),
+        }
+    }
+
+    /// Uses `DOCKER_CONFIG` or `~/.docker`, with `DOCKER_HOST` as the default context's host.
+    pub fn from_env() -> Self {
+        let var = |key: &str| env::var(key).ok().filter(|v| !v.is_empty());
+        let config_dir = var("DOCKER_CONFIG")
+            .map(PathBuf::from)
+            .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".docker")))
+            .unwrap_or_else(|| PathBuf::from(".docker"));
+        let default_host = var("DOCKER_HOST").unwrap_or_else(|| DEFAULT_ENDPOINT.to_string());
+        Self::new(config_dir, default_host)
+    }
+
+    /// The context selected with `docker context use`, if any.
+    pub fn current(&self) -> Option<String> {
+        let bytes = fs::read(self.config_dir.join("config.json")).ok()?;
+        let config: types::ConfigFile = serde_json::from_slice(&bytes).ok()?;
+        config.current_context.filter(|name| !name.is_empty())
+    }
+
+    pub fn endpoint(&self, name: &str) -> Result<ContextEndpoint, Box<dyn Error + Send + Sync>> {
+        let (id, meta) = self
+            .metas()
+            .into_iter()
+            .find(|(_, meta)| meta.name == name)
+            .ok_or_else(|| format!("context {} not found", name))?;
+        let docker = meta
+            .endpoints
+            .docker
+            .ok_or_else(|| format!("context {} has no docker endpoint", name))?;
+        let mut endpoint: Endpoint = docker.host.parse()?;
+
+        let tls_dir = self.config_dir.join("contexts").join("tls").join(id).join("docker");
+        let tls = if tls_dir.is_dir() {
+            if let Endpoint::Tcp { tls, .. } = &mut endpoint {
+                *tls = true;
+            }
+            Some(TlsConfig {
+                cert_path: tls_dir,
+                verify: !docker.skip_tls_verify,
+            })
+        } else {
+            None
+        };
+        Ok(ContextEndpoint { endpoint, tls })
+    }
+
+    /// Every readable `meta.json` with the name of its directory.
+    fn metas(&self) -> Vec<(String, types::Meta)> {
+        let dir = self.config_dir.join("contexts").join("meta");
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => return vec![],
+        };
+        let mut metas: Vec<_> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let id = entry.file_name().to_string_lossy().to_string();
+                read_meta(&entry.path().join("meta.json")).map(|meta| (id, meta))
+            })
+            .collect();
+        metas.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+        metas
+    }
+}
+
+#[async_trait]
+impl ContextRepository for FileContextRepository {
+    async fn list(&self) -> Result<Vec<Context>, Box<dyn Error + Send + Sync>> {
+        let current = self.current().unwrap_or_else(|| DEFAULT_CONTEXT.to_string());
+        let mut contexts = vec![Context {
+            name: DEFAULT_CONTEXT.to_string(),
+            description: "Current DOCKER_HOST based configuration".to_string(),
+            host: self.default_host.clone(),
+            current: current == DEFAULT_CONTEXT,
+        }];
+        for (_, meta) in self.metas() {
+            contexts.push(Context {
+                current: meta.name == current,
+                description: meta.metadata.and_then(|m| m.description).unwrap_or_default(),
+                host: meta.endpoints.docker.map(|d| d.host).unwrap_or_default(),
+                name: meta.name,
+            });
+        }
+        Ok(contexts)
+    }
+}
+
+fn read_meta(path: &Path) -> Option<types::Meta> {
+    let bytes = fs::read(path).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+mod types {
+    use serde::Deserialize;
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct ConfigFile {
+        #[serde(rename = "currentContext")]
+        pub current_context: Option<String>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Meta {
+        #[serde(rename = "Name")]
+        pub name: String,
+        #[serde(rename = "Metadata")]
+        pub metadata: Option<Metadata>,
+        #[serde(rename = "Endpoints", default)]
+        pub endpoints: Endpoints,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Metadata {
+        #[serde(rename = "Description")]
+        pub description: Option<String>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Endpoints {
+        pub docker: Option<DockerEndpoint>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct DockerEndpoint {
+        #[serde(rename = "Host")]
+        pub host: String,
+        #[serde(rename = "SkipTLSVerify", default)]
+        pub skip_tls_verify: bool,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileContextRepository;
+    use crate::webapi::rest::client::{Endpoint, TlsConfig};
+    use std::fs;
+
+    #[test]
+    fn reading_stored_contexts() {
+        let dir = std::env::temp_dir().join(format!("harpoon-contexts-{}", std::process::id()));
+        let id = "0123abcd";
+        fs::create_dir_all(dir.join("contexts/meta").join(id)).unwrap();
+        fs::create_dir_all(dir.join("contexts/tls").join(id).join("docker")).unwrap();
+        fs::write(dir.join("config.json"), r#"{"currentContext": "remote"}"#).unwrap();
+        fs::write(
+            dir.join("contexts/meta").join(id).join("meta.json"),
+            r#"{"Name":"remote","Metadata":{},"Endpoints":{"docker":{"Host":"tcp://10.0.0.1:2376","SkipTLSVerify":true}}}"#,
+        )
+        .unwrap();
+
+        let repository = FileContextRepository::new(&dir, "unix:///var/run/docker.sock");
+        assert_eq!(repository.current(), Some("remote".to_string()));
+        let endpoint = repository.endpoint("remote").unwrap();
+        assert_eq!(
+            endpoint.endpoint,
+            Endpoint::Tcp {
+                host: "10.0.0.1".to_string(),
+                port: Some(2376),
+                tls: true
+            }
+        );
+        assert_eq!(
+            endpoint.tls,
+            Some(TlsConfig {
+                cert_path: dir.join("contexts/tls").join(id).join("docker"),
+                verify: false
+            })
+        );
+        assert!(repository.endpoint("missing").is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
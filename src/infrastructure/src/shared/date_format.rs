@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, Utc};
 use serde::{self, Deserialize, Deserializer};
 
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
@@ -6,8 +6,5 @@ where
     D: Deserializer<'de>,
 {
     let n = i64::deserialize(deserializer)?;
-    Ok(Some(DateTime::<Utc>::from_utc(
-        NaiveDateTime::from_timestamp(n, 0),
-        Utc,
-    )))
+    Ok(DateTime::<Utc>::from_timestamp(n, 0))
 }
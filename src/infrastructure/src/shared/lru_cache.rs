@@ -0,0 +1,125 @@
+//! A map of bounded size whose entries expire, for keeping responses of the daemon around.
+//!
+//! When full, storing another key drops the least recently used entry. Lookups are hashed, while finding
+//! the entry to drop scans every entry, which is cheap for the few hundred entries a view needs.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+pub struct LruCache<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    capacity: usize,
+    ttl: Duration,
+    /// Incremented on every access, so the smallest `used` marks the least recently used entry.
+    clock: u64,
+}
+
+struct Entry<V> {
+    value: V,
+    stored: Instant,
+    used: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// Keeps up to `capacity` entries, each for `ttl` after it was stored.
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity,
+            ttl,
+            clock: 0,
+        }
+    }
+
+    /// The value stored for the key unless it expired, which counts as a use.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let expired = self.entries.get(key)?.stored.elapsed() >= self.ttl;
+        if expired {
+            self.entries.remove(key);
+            return None;
+        }
+        self.clock += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.used = self.clock;
+        Some(&entry.value)
+    }
+
+    pub fn put(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            self.evict();
+        }
+        self.clock += 1;
+        let entry = Entry {
+            value,
+            stored: Instant::now(),
+            used: self.clock,
+        };
+        self.entries.insert(key, entry);
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key).map(|entry| entry.value)
+    }
+
+    /// Keeps only the entries for which `f` returns true.
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &V) -> bool) {
+        self.entries.retain(|key, entry| f(key, &entry.value));
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drops the expired entries, or the least recently used one when none expired.
+    fn evict(&mut self) {
+        let ttl = self.ttl;
+        self.entries.retain(|_, entry| entry.stored.elapsed() < ttl);
+        if self.entries.len() < self.capacity {
+            return;
+        }
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.used)
+            .map(|(key, _)| key.clone());
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LruCache;
+    use std::time::Duration;
+
+    #[test]
+    fn evicting_the_least_recently_used() {
+        let mut cache = LruCache::new(2, Duration::from_secs(60));
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        cache.put("c", 3);
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.get(&"c"), Some(&3));
+        assert_eq!(cache.len(), 2);
+
+        let mut expiring = LruCache::new(2, Duration::from_secs(0));
+        expiring.put("a", 1);
+        assert_eq!(expiring.get(&"a"), None);
+        assert!(expiring.is_empty());
+    }
+}
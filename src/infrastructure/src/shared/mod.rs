@@ -1 +1,3 @@
 pub mod date_format;
+pub mod lru_cache;
+pub mod stdcopy;
@@ -0,0 +1,127 @@
+//! Demultiplexer for the stream format Docker uses on `/logs` and `/attach` of non-TTY containers.
+//!
+//! Each frame starts with an 8-byte header: `[STREAM_TYPE, 0, 0, 0, SIZE1, SIZE2, SIZE3, SIZE4]`,
+//! where the size is a big-endian `u32` giving the length of the payload which follows.
+
+const HEADER_LEN: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StdStream {
+    Stdin,
+    Stdout,
+    Stderr,
+}
+
+#[derive(Default)]
+pub struct Demuxer {
+    buf: Vec<u8>,
+}
+
+impl Demuxer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a chunk read from the connection and returns every frame it completed.
+    /// A frame split across chunks is kept until the rest of it arrives.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<(StdStream, Vec<u8>)> {
+        self.buf.extend_from_slice(chunk);
+
+        let mut frames = Vec::new();
+        let mut pos = 0;
+        while self.buf.len() - pos >= HEADER_LEN {
+            let header = &self.buf[pos..pos + HEADER_LEN];
+            let size = u32::from_be_bytes([header[4], header[5], header[6], header[7]]) as usize;
+            if self.buf.len() - pos - HEADER_LEN < size {
+                break;
+            }
+            let stream = match header[0] {
+                0 => StdStream::Stdin,
+                2 => StdStream::Stderr,
+                _ => StdStream::Stdout,
+            };
+            let start = pos + HEADER_LEN;
+            frames.push((stream, self.buf[start..start + size].to_vec()));
+            pos = start + size;
+        }
+        self.buf.drain(..pos);
+        frames
+    }
+}
+
+/// Accumulates payload bytes and hands back complete lines without their line terminator.
+#[derive(Default)]
+pub struct LineBuffer {
+    buf: Vec<u8>,
+}
+
+impl LineBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, data: &[u8]) -> Vec<String> {
+        self.buf.extend_from_slice(data);
+
+        let mut lines = Vec::new();
+        while let Some(idx) = self.buf.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buf.drain(..=idx).collect();
+            lines.push(to_line(&line[..line.len() - 1]));
+        }
+        lines
+    }
+
+    /// Returns the trailing text which was not terminated by a newline, if any.
+    pub fn flush(&mut self) -> Option<String> {
+        if self.buf.is_empty() {
+            return None;
+        }
+        let line = to_line(&self.buf);
+        self.buf.clear();
+        Some(line)
+    }
+}
+
+fn to_line(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Demuxer, LineBuffer, StdStream};
+
+    fn frame(stream: u8, payload: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![stream, 0, 0, 0];
+        bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    #[test]
+    fn demuxing_frames_split_across_chunks() {
+        let mut bytes = frame(1, b"hello\n");
+        bytes.extend(frame(2, b"oops\n"));
+        let mut demuxer = Demuxer::new();
+
+        let first = demuxer.push(&bytes[..10]);
+        assert!(first.is_empty());
+        let rest = demuxer.push(&bytes[10..]);
+        assert_eq!(
+            rest,
+            vec![
+                (StdStream::Stdout, b"hello\n".to_vec()),
+                (StdStream::Stderr, b"oops\n".to_vec())
+            ]
+        );
+    }
+
+    #[test]
+    fn buffering_partial_lines() {
+        let mut buffer = LineBuffer::new();
+        assert_eq!(buffer.push(b"first\r\nsec"), vec!["first".to_string()]);
+        assert_eq!(buffer.push(b"ond\nthi"), vec!["second".to_string()]);
+        assert_eq!(buffer.flush(), Some("thi".to_string()));
+        assert_eq!(buffer.flush(), None);
+    }
+}
@@ -0,0 +1,228 @@
+use crate::shared::lru_cache::LruCache;
+use async_trait::async_trait;
+use domain::cache::Cache;
+use domain::image::{Image, ImageHistory, ImageRemoveReport, ImageRepository, ImageSummary, PullStream};
+use std::error::Error;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Images whose details are kept at most.
+const CAPACITY: usize = 512;
+/// How long details are kept, in case an event which changed them was missed.
+const TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Keeps the details and history of images another repository fetched, so revisiting an image doesn't
+/// ask the daemon again. Lists are always fetched. Removing, tagging and pulling through it forget the
+/// images concerned; changes made elsewhere have to be reported through `Cache`.
+pub struct CachedImageRepository<R: ImageRepository> {
+    inner: R,
+    state: Mutex<CacheState>,
+}
+
+struct CacheState {
+    images: LruCache<String, Image>,
+    histories: LruCache<String, ImageHistory>,
+}
+
+impl<R: ImageRepository> CachedImageRepository<R> {
+    pub fn new(inner: R) -> Self {
+        Self::with_limits(inner, CAPACITY, TTL)
+    }
+
+    pub fn with_limits(inner: R, capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner,
+            state: Mutex::new(CacheState {
+                images: LruCache::new(capacity, ttl),
+                histories: LruCache::new(capacity, ttl),
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, CacheState> {
+        // The state stays consistent even if a thread panicked while holding the lock.
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<R: ImageRepository + Send + Sync> Cache for CachedImageRepository<R> {
+    /// Forgets the image with the ID, and every image tagged with the reference.
+    fn invalidate(&self, key: &str) {
+        let mut state = self.state();
+        let mut ids = vec![key.to_string()];
+        state.images.retain(|id, image| {
+            let concerned = id == key || image.repo_tags.iter().any(|t| t == key);
+            if concerned {
+                ids.push(id.clone());
+            }
+            !concerned
+        });
+        for id in ids.iter() {
+            state.histories.remove(id);
+        }
+    }
+
+    fn clear(&self) {
+        let mut state = self.state();
+        state.images.clear();
+        state.histories.clear();
+    }
+}
+
+#[async_trait]
+impl<R: ImageRepository + Send + Sync> ImageRepository for CachedImageRepository<R> {
+    async fn list(&self) -> Result<Vec<ImageSummary>, Box<dyn Error + Send + Sync>> {
+        self.inner.list().await
+    }
+
+    async fn inspect(&self, id: String) -> Result<Image, Box<dyn Error + Send + Sync>> {
+        if let Some(image) = self.state().images.get(&id) {
+            return Ok(image.clone());
+        }
+        let image = self.inner.inspect(id.clone()).await?;
+        self.state().images.put(id, image.clone());
+        Ok(image)
+    }
+
+    async fn history(&self, id: String) -> Result<ImageHistory, Box<dyn Error + Send + Sync>> {
+        if let Some(history) = self.state().histories.get(&id) {
+            return Ok(history.clone());
+        }
+        let history = self.inner.history(id.clone()).await?;
+        self.state().histories.put(id, history.clone());
+        Ok(history)
+    }
+
+    /// The pulled tag may move away from an image whose details are kept.
+    async fn pull(&self, reference: String) -> Result<PullStream, Box<dyn Error + Send + Sync>> {
+        self.invalidate(&with_default_tag(&reference));
+        self.inner.pull(reference).await
+    }
+
+    async fn remove(
+        &self,
+        id: String,
+        force: bool,
+        no_prune: bool,
+    ) -> Result<ImageRemoveReport, Box<dyn Error + Send + Sync>> {
+        let report = self.inner.remove(id.clone(), force, no_prune).await?;
+        self.invalidate(&id);
+        report
+            .untagged
+            .iter()
+            .chain(report.deleted.iter())
+            .for_each(|key| self.invalidate(key));
+        Ok(report)
+    }
+
+    async fn tag(&self, id: String, repo: String, tag: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let reference = format!("{}:{}", repo, tag);
+        self.inner.tag(id.clone(), repo, tag).await?;
+        // The tag moves away from the image which had it before.
+        self.invalidate(&reference);
+        self.invalidate(&id);
+        Ok(())
+    }
+}
+
+/// The reference as images are tagged by it, e.g. `busybox:latest` for `busybox`, since pulling without a tag
+/// or digest pulls `latest`. A registry's port, as in `localhost:5000/busybox`, isn't a tag.
+fn with_default_tag(reference: &str) -> String {
+    let name = reference.rsplit('/').next().unwrap_or(reference);
+    if name.contains(':') || name.contains('@') {
+        reference.to_string()
+    } else {
+        format!("{}:latest", reference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{with_default_tag, CachedImageRepository};
+    use crate::webapi::inmem::image_repository::FakeImageRepository;
+    use async_trait::async_trait;
+    use domain::cache::Cache;
+    use domain::image::{Image, ImageHistory, ImageRemoveReport, ImageRepository, ImageSummary, PullStream};
+    use std::error::Error;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Counts the inspections which reach the daemon.
+    struct CountingRepository {
+        fake: FakeImageRepository,
+        inspections: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl ImageRepository for CountingRepository {
+        async fn list(&self) -> Result<Vec<ImageSummary>, Box<dyn Error + Send + Sync>> {
+            self.fake.list().await
+        }
+        async fn inspect(&self, id: String) -> Result<Image, Box<dyn Error + Send + Sync>> {
+            self.inspections.fetch_add(1, Ordering::SeqCst);
+            self.fake.inspect(id).await
+        }
+        async fn history(&self, id: String) -> Result<ImageHistory, Box<dyn Error + Send + Sync>> {
+            self.fake.history(id).await
+        }
+        async fn pull(&self, reference: String) -> Result<PullStream, Box<dyn Error + Send + Sync>> {
+            self.fake.pull(reference).await
+        }
+        async fn remove(
+            &self,
+            id: String,
+            force: bool,
+            no_prune: bool,
+        ) -> Result<ImageRemoveReport, Box<dyn Error + Send + Sync>> {
+            self.fake.remove(id, force, no_prune).await
+        }
+        async fn tag(&self, id: String, repo: String, tag: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+            self.fake.tag(id, repo, tag).await
+        }
+    }
+
+    #[tokio::test]
+    async fn inspecting_from_the_cache_until_invalidated() {
+        let inspections = Arc::new(AtomicUsize::new(0));
+        let inner = CountingRepository {
+            fake: FakeImageRepository::new(),
+            inspections: inspections.clone(),
+        };
+        let repository = CachedImageRepository::with_limits(inner, 1, Duration::from_secs(60));
+        let inspect = |id: &str| repository.inspect(id.to_string());
+
+        inspect("sha256:busybox").await.unwrap();
+        inspect("sha256:busybox").await.unwrap();
+        assert_eq!(inspections.load(Ordering::SeqCst), 1);
+
+        // Tags of a kept image reach it too.
+        repository.invalidate("busybox:1.32");
+        inspect("sha256:busybox").await.unwrap();
+        assert_eq!(inspections.load(Ordering::SeqCst), 2);
+
+        // Only one image fits.
+        inspect("sha256:alpine").await.unwrap();
+        inspect("sha256:busybox").await.unwrap();
+        assert_eq!(inspections.load(Ordering::SeqCst), 4);
+
+        repository.clear();
+        inspect("sha256:busybox").await.unwrap();
+        assert_eq!(inspections.load(Ordering::SeqCst), 5);
+        // Pulling without a tag pulls `latest`.
+        let _ = repository.pull("busybox".to_string()).await.unwrap();
+        inspect("sha256:busybox").await.unwrap();
+        assert_eq!(inspections.load(Ordering::SeqCst), 6);
+    }
+
+    #[test]
+    fn defaulting_to_the_latest_tag() {
+        assert_eq!(with_default_tag("busybox"), "busybox:latest");
+        assert_eq!(with_default_tag("busybox:1.32"), "busybox:1.32");
+        assert_eq!(
+            with_default_tag("localhost:5000/busybox"),
+            "localhost:5000/busybox:latest"
+        );
+        assert_eq!(with_default_tag("busybox@sha256:c5439d7d"), "busybox@sha256:c5439d7d");
+    }
+}
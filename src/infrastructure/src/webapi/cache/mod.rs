@@ -0,0 +1 @@
+pub mod image_repository;
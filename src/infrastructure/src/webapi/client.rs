@@ -1,7 +1,53 @@
+use crate::webapi::request::{ApiRequest, ApiResponse, RequestBody};
 use async_trait::async_trait;
+use futures_util::stream::Stream;
+use hyper::upgrade::Upgraded;
 use std::error::Error;
+use std::pin::Pin;
+
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Vec<u8>, Box<dyn Error + Send + Sync>>> + Send>>;
 
 #[async_trait]
-pub trait Client {
-    async fn get(&self, path: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>>;
+pub trait Client: Send + Sync {
+    /// Sends the request and reads the whole response. Error statuses other than 304 fail with `ApiError`.
+    async fn request(&self, request: ApiRequest) -> Result<ApiResponse, Box<dyn Error + Send + Sync>>;
+    /// Sends the request and yields the response body chunk by chunk as it arrives, for endpoints which
+    /// follow like logs, stats and events. Dropping the stream closes the connection.
+    async fn stream(&self, request: ApiRequest) -> Result<ByteStream, Box<dyn Error + Send + Sync>>;
+    /// Sends a POST request asking the daemon to hijack the connection, as `/exec/{id}/start` and
+    /// `/containers/{id}/attach` do, and returns the raw bidirectional stream.
+    async fn upgrade(&self, path: &str, body: Vec<u8>) -> Result<Upgraded, Box<dyn Error + Send + Sync>>;
+
+    async fn get(&self, path: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        Ok(self.request(ApiRequest::get(path)).await?.body)
+    }
+
+    async fn post(&self, path: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        Ok(self.request(ApiRequest::post(path)).await?.body)
+    }
+
+    async fn post_json(&self, path: &str, body: Vec<u8>) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        let request = ApiRequest {
+            body: RequestBody::Json(body),
+            ..ApiRequest::post(path)
+        };
+        Ok(self.request(request).await?.body)
+    }
+
+    async fn put(&self, path: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        Ok(self.request(ApiRequest::put(path)).await?.body)
+    }
+
+    async fn delete(&self, path: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+        Ok(self.request(ApiRequest::delete(path)).await?.body)
+    }
+
+    async fn get_stream(&self, path: &str) -> Result<ByteStream, Box<dyn Error + Send + Sync>> {
+        self.stream(ApiRequest::get(path)).await
+    }
+
+    /// Only the status and headers, e.g. to check whether an image exists.
+    async fn head(&self, path: &str) -> Result<ApiResponse, Box<dyn Error + Send + Sync>> {
+        self.request(ApiRequest::head(path)).await
+    }
 }
@@ -0,0 +1,155 @@
+use domain::error::{ErrorKind, RepositoryError};
+use hyper::StatusCode;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Failure of a request to the Docker daemon, either reported by the daemon or on the way to it.
+/// Repositories return it wrapped in a `RepositoryError`, so that callers tell the cases apart by its kind.
+#[derive(Debug)]
+pub enum ApiError {
+    /// 404, e.g. the container was removed in the meantime.
+    NotFound(String),
+    /// 409, e.g. removing an image a container still uses.
+    Conflict(String),
+    /// 401 and 403.
+    Unauthorized(String),
+    /// Any other error status with the daemon's message.
+    ServerError {
+        status: StatusCode,
+        message: String,
+    },
+    ConnectionRefused,
+    /// The user may not open the daemon's socket.
+    PermissionDenied,
+    Timeout,
+    /// Any other transport failure.
+    Connection(Box<dyn Error + Send + Sync>),
+}
+
+impl ApiError {
+    /// Builds the error for a response with an error status from its JSON body `{"message": "..."}`.
+    pub fn from_response(status: StatusCode, body: &[u8]) -> Self {
+        let message = serde_json::from_slice::<ErrorResponse>(body)
+            .map(|r| r.message)
+            .unwrap_or_else(|_| String::from_utf8_lossy(body).trim().to_string());
+        match status {
+            StatusCode::NOT_FOUND => ApiError::NotFound(message),
+            StatusCode::CONFLICT => ApiError::Conflict(message),
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => ApiError::Unauthorized(message),
+            _ => ApiError::ServerError { status, message },
+        }
+    }
+
+    /// Classifies a transport failure by the I/O error it comes from, if any.
+    pub fn from_transport(error: Box<dyn Error + Send + Sync>) -> Self {
+        let kind = {
+            let mut source: Option<&(dyn Error + 'static)> = Some(error.as_ref());
+            let mut kind = None;
+            while let Some(e) = source {
+                if let Some(io_error) = e.downcast_ref::<io::Error>() {
+                    kind = Some(io_error.kind());
+                    break;
+                }
+                source = e.source();
+            }
+            kind
+        };
+        match kind {
+            // A missing socket means the daemon isn't running, just like a refused connection.
+            Some(io::ErrorKind::ConnectionRefused) | Some(io::ErrorKind::NotFound) => ApiError::ConnectionRefused,
+            Some(io::ErrorKind::PermissionDenied) => ApiError::PermissionDenied,
+            Some(io::ErrorKind::TimedOut) => ApiError::Timeout,
+            _ => ApiError::Connection(error),
+        }
+    }
+}
+
+impl From<ApiError> for RepositoryError {
+    fn from(error: ApiError) -> Self {
+        let kind = match error {
+            ApiError::NotFound(_) => ErrorKind::NotFound,
+            ApiError::Conflict(_) => ErrorKind::Conflict,
+            ApiError::Unauthorized(_) | ApiError::PermissionDenied => ErrorKind::Unauthorized,
+            ApiError::ConnectionRefused | ApiError::Timeout | ApiError::Connection(_) => ErrorKind::Unavailable,
+            ApiError::ServerError { .. } => ErrorKind::Other,
+        };
+        RepositoryError::new(kind, error)
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::NotFound(message) => write!(f, "Not found: {}", message),
+            ApiError::Conflict(message) => write!(f, "Conflict: {}", message),
+            ApiError::Unauthorized(message) => write!(f, "Unauthorized: {}", message),
+            ApiError::ServerError { status, message } => write!(f, "Docker daemon error ({}): {}", status, message),
+            ApiError::ConnectionRefused => {
+                write!(f, "Cannot connect to the Docker daemon. Is the docker daemon running?")
+            }
+            ApiError::PermissionDenied => write!(
+                f,
+                "Permission denied while connecting to the Docker daemon socket. Is your user in the docker group?"
+            ),
+            ApiError::Timeout => write!(f, "The Docker daemon did not respond in time"),
+            ApiError::Connection(e) => write!(f, "Failed to reach the Docker daemon: {}", e),
+        }
+    }
+}
+
+impl Error for ApiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ApiError::Connection(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApiError;
+    use domain::error::{ErrorKind, RepositoryError};
+    use hyper::StatusCode;
+    use std::error::Error;
+    use std::io;
+
+    #[test]
+    fn reading_daemon_messages() {
+        let error = ApiError::from_response(StatusCode::NOT_FOUND, br#"{"message":"No such container: abc"}"#);
+        assert_eq!(error.to_string(), "Not found: No such container: abc");
+        let error = ApiError::from_response(StatusCode::INTERNAL_SERVER_ERROR, b"oops\n");
+        assert!(matches!(error, ApiError::ServerError { message, .. } if message == "oops"));
+    }
+
+    #[test]
+    fn classifying_transport_failures() {
+        let refused = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+        assert!(matches!(
+            ApiError::from_transport(Box::new(refused)),
+            ApiError::ConnectionRefused
+        ));
+        let denied = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        assert!(matches!(
+            ApiError::from_transport(Box::new(denied)),
+            ApiError::PermissionDenied
+        ));
+    }
+
+    #[test]
+    fn keeping_the_kind_for_callers() {
+        let error: Box<dyn Error + Send + Sync> =
+            RepositoryError::from(ApiError::Conflict("image is being used".to_string())).into();
+        assert_eq!(ErrorKind::of(error.as_ref()), ErrorKind::Conflict);
+        assert_eq!(error.to_string(), "Conflict: image is being used");
+        let plain: Box<dyn Error + Send + Sync> = "oops".into();
+        assert_eq!(ErrorKind::of(plain.as_ref()), ErrorKind::Other);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::webapi::error::ApiError;
+use async_trait::async_trait;
+use chrono::Utc;
+use domain::container::{
+    Container, ContainerConfig, ContainerRepository, ContainerState, ContainerSummary, HostConfig, Mount,
+    NetworkSettings, RestartPolicy,
+};
+use domain::error::RepositoryError;
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::Mutex;
+
+/// A single container `fake`, running at first, whose state follows the actions run on it.
+pub struct FakeContainerRepository {
+    state: Mutex<String>,
+}
+
+impl FakeContainerRepository {
+    pub fn new() -> Self {
+        FakeContainerRepository {
+            state: Mutex::new("running".to_string()),
+        }
+    }
+
+    fn state(&self) -> String {
+        self.state.lock().unwrap().clone()
+    }
+
+    fn transition(&self, id: String, state: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if id != "fake" {
+            return Err(not_found(&id));
+        }
+        *self.state.lock().unwrap() = state.to_string();
+        Ok(())
+    }
+}
+
+impl Default for FakeContainerRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ContainerRepository for FakeContainerRepository {
+    async fn list(&self) -> Result<Vec<ContainerSummary>, Box<dyn Error + Send + Sync>> {
+        let state = self.state();
+        let status = match state.as_str() {
+            "running" => "Up 5 minutes",
+            "paused" => "Up 5 minutes (Paused)",
+            _ => "Exited (0) 1 second ago",
+        };
+        let summary = ContainerSummary::from_repository(
+            "fake".to_string(),
+            vec!["/fake".to_string()],
+            "busybox:latest".to_string(),
+            "sh".to_string(),
+            Utc::now(),
+            state,
+            status.to_string(),
+            vec![],
+            HashMap::new(),
+        );
+        Ok(vec![summary])
+    }
+
+    /// The fake container mounts the volume `data`.
+    async fn list_by_volume(&self, volume: String) -> Result<Vec<ContainerSummary>, Box<dyn Error + Send + Sync>> {
+        if volume == "data" {
+            self.list().await
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    async fn inspect(&self, id: String) -> Result<Container, Box<dyn Error + Send + Sync>> {
+        if id != "fake" {
+            return Err(not_found(&id));
+        }
+        let state = self.state();
+        Ok(Container {
+            id: id.clone(),
+            name: id,
+            image: "busybox:latest".to_string(),
+            created: Utc::now(),
+            state: ContainerState {
+                running: state != "exited",
+                paused: state == "paused",
+                status: state,
+                restarting: false,
+                oom_killed: false,
+                dead: false,
+                pid: 1,
+                exit_code: 0,
+                error: String::new(),
+                started_at: String::new(),
+                finished_at: String::new(),
+                health: None,
+            },
+            config: ContainerConfig {
+                hostname: String::new(),
+                user: String::new(),
+                env: vec![],
+                cmd: vec!["sh".to_string()],
+                entrypoint: vec![],
+                working_dir: String::new(),
+                labels: HashMap::new(),
+                tty: true,
+                open_stdin: true,
+                exposed_ports: vec![],
+            },
+            host_config: HostConfig {
+                network_mode: "default".to_string(),
+                privileged: false,
+                binds: vec![],
+                restart_policy: RestartPolicy {
+                    name: "on-failure".to_string(),
+                    maximum_retry_count: 3,
+                },
+            },
+            mounts: vec![Mount {
+                mount_type: "volume".to_string(),
+                name: Some("data".to_string()),
+                source: "/var/lib/docker/volumes/data/_data".to_string(),
+                destination: "/data".to_string(),
+                driver: Some("local".to_string()),
+                mode: "z".to_string(),
+                rw: true,
+            }],
+            network_settings: NetworkSettings {
+                ports: vec![],
+                networks: HashMap::new(),
+            },
+        })
+    }
+
+    async fn start(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.transition(id, "running")
+    }
+
+    async fn stop(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.transition(id, "exited")
+    }
+
+    async fn restart(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.transition(id, "running")
+    }
+
+    async fn kill(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.transition(id, "exited")
+    }
+
+    async fn pause(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.transition(id, "paused")
+    }
+
+    async fn unpause(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.transition(id, "running")
+    }
+}
+
+fn not_found(id: &str) -> Box<dyn Error + Send + Sync> {
+    RepositoryError::from(ApiError::NotFound(format!("No such container: {}", id))).into()
+}
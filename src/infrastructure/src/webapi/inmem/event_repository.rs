@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::event::{EventKind, EventRepository, EventStream, SystemEvent};
+use futures_util::stream;
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct FakeEventRepository {}
+
+impl FakeEventRepository {
+    pub fn new() -> Self {
+        FakeEventRepository {}
+    }
+}
+
+impl Default for FakeEventRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EventRepository for FakeEventRepository {
+    /// Yields a container being started and running out of memory, then an image being pulled.
+    async fn events(&self, _since: Option<DateTime<Utc>>) -> Result<EventStream, Box<dyn Error + Send + Sync>> {
+        let event = |kind: EventKind, action: &str, actor_id: &str, name: &str| {
+            let mut attributes = HashMap::new();
+            attributes.insert("name".to_string(), name.to_string());
+            Ok(SystemEvent {
+                kind,
+                action: action.to_string(),
+                actor_id: actor_id.to_string(),
+                attributes,
+                time: Utc::now(),
+            })
+        };
+        let events = vec![
+            event(EventKind::Container, "start", "fake", "web"),
+            event(EventKind::Container, "exec_start: sh", "fake", "web"),
+            event(EventKind::Container, "oom", "fake", "web"),
+            event(EventKind::Image, "pull", "nginx:latest", "nginx"),
+        ];
+        Ok(Box::pin(stream::iter(events)))
+    }
+}
@@ -1,5 +1,13 @@
+use crate::webapi::error::ApiError;
 use async_trait::async_trait;
-use domain::image::{Image, ImageHistory, ImageRepository, ImageSummary};
+use chrono::Utc;
+use domain::error::RepositoryError;
+use domain::image::{
+    Image, ImageHistory, ImageRecord, ImageRemoveReport, ImageRepository, ImageSummary, PullProgress, PullStream,
+};
+use domain::size::ByteSize;
+use futures_util::stream;
+use std::collections::HashMap;
 use std::error::Error;
 
 pub struct FakeImageRepository {}
@@ -10,17 +18,113 @@ impl FakeImageRepository {
     }
 }
 
+impl Default for FakeImageRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait]
 impl ImageRepository for FakeImageRepository {
+    /// `busybox` with two tags, an image only known by its digest and a dangling one.
     async fn list(&self) -> Result<Vec<ImageSummary>, Box<dyn Error + Send + Sync>> {
-        unimplemented!();
+        let image = |id: &str, repo_tags: Vec<&str>, repo_digests: Vec<&str>| ImageSummary {
+            id: format!("sha256:{}", id),
+            repo_tags: repo_tags.into_iter().map(|v| v.to_string()).collect(),
+            repo_digests: repo_digests.into_iter().map(|v| v.to_string()).collect(),
+            parent_id: None,
+            created: Utc::now(),
+            size: ByteSize(1_000),
+            shared_size: Some(ByteSize(0)),
+            containers: None,
+        };
+        Ok(vec![
+            image(
+                "busybox",
+                vec!["busybox:latest", "busybox:1.32"],
+                vec!["busybox@sha256:c5439d7db88ab5423999530349d327b04279ad3161d7596d2126dfb5b02bfd1f"],
+            ),
+            image(
+                "alpine",
+                vec![],
+                vec!["alpine@sha256:074d3636ebda6dd446d0d00304c4454f468237fdacf08fb0eeac90bdbfa1bac7"],
+            ),
+            image("dangling", vec![], vec![]),
+        ])
     }
-    #[allow(unused_variables)]
+
+    /// Any image of the list, by ID or tag.
     async fn inspect(&self, id: String) -> Result<Image, Box<dyn Error + Send + Sync>> {
-        unimplemented!();
+        let image = self
+            .list()
+            .await?
+            .into_iter()
+            .find(|v| v.id == id || v.repo_tags.contains(&id))
+            .ok_or_else(|| RepositoryError::from(ApiError::NotFound(format!("No such image: {}", id))))?;
+        Ok(Image {
+            id: image.id,
+            repo_tags: image.repo_tags,
+            os: "linux".to_string(),
+            architecture: "amd64".to_string(),
+            env: vec!["PATH=/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin".to_string()],
+            entrypoint: vec![],
+            cmd: vec!["sh".to_string()],
+            labels: HashMap::new(),
+        })
     }
-    #[allow(unused_variables)]
+
     async fn history(&self, id: String) -> Result<ImageHistory, Box<dyn Error + Send + Sync>> {
-        unimplemented!();
+        let image = self.inspect(id).await?;
+        Ok(vec![ImageRecord {
+            id: image.id,
+            created_by: "/bin/sh -c #(nop)  CMD [\"sh\"]".to_string(),
+            size: ByteSize(1_000),
+        }])
+    }
+
+    /// Downloads and extracts one layer of `busybox`; any other image doesn't exist.
+    async fn pull(&self, reference: String) -> Result<PullStream, Box<dyn Error + Send + Sync>> {
+        let progress = |id: Option<&str>, status: &str, current: Option<u64>, total: Option<u64>| {
+            Ok(PullProgress {
+                id: id.map(|v| v.to_string()),
+                status: status.to_string(),
+                current,
+                total,
+            })
+        };
+        let messages = if reference.starts_with("busybox") {
+            vec![
+                progress(Some("latest"), "Pulling from library/busybox", None, None),
+                progress(Some("3cb635b06aa2"), "Pulling fs layer", None, None),
+                progress(Some("3cb635b06aa2"), "Downloading", Some(512), Some(2048)),
+                progress(Some("3cb635b06aa2"), "Extracting", Some(2048), Some(2048)),
+                progress(Some("3cb635b06aa2"), "Pull complete", None, None),
+                progress(None, "Status: Downloaded newer image for busybox:latest", None, None),
+            ]
+        } else {
+            vec![Err(format!("pull access denied for {}", reference).into())]
+        };
+        Ok(Box::pin(stream::iter(messages)))
+    }
+
+    /// `busybox:latest` is used by a container, so only a forced removal deletes it.
+    async fn remove(
+        &self,
+        id: String,
+        force: bool,
+        _no_prune: bool,
+    ) -> Result<ImageRemoveReport, Box<dyn Error + Send + Sync>> {
+        if id == "busybox:latest" && !force {
+            let message = "conflict: unable to remove repository reference \"busybox:latest\" (must force)";
+            return Err(RepositoryError::from(ApiError::Conflict(message.to_string())).into());
+        }
+        Ok(ImageRemoveReport {
+            untagged: vec![id.clone()],
+            deleted: vec![format!("sha256:{}", id)],
+        })
+    }
+
+    async fn tag(&self, _id: String, _repo: String, _tag: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(())
     }
 }
@@ -1 +1,7 @@
+pub mod container_repository;
+pub mod event_repository;
 pub mod image_repository;
+pub mod network_repository;
+pub mod stats_repository;
+pub mod system_repository;
+pub mod volume_repository;
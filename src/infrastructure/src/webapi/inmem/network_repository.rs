@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use domain::network::{
+    CreateNetworkOptions, Ipam, IpamConfig, Network, NetworkEndpoint, NetworkPruneReport, NetworkRepository,
+};
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct FakeNetworkRepository {}
+
+impl FakeNetworkRepository {
+    pub fn new() -> Self {
+        FakeNetworkRepository {}
+    }
+}
+
+impl Default for FakeNetworkRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn network(name: &str, subnet: &str, containers: HashMap<String, NetworkEndpoint>) -> Network {
+    Network {
+        id: format!("{}-id", name),
+        name: name.to_string(),
+        driver: "bridge".to_string(),
+        scope: "local".to_string(),
+        created: None,
+        internal: false,
+        attachable: false,
+        ipam: Ipam {
+            driver: "default".to_string(),
+            config: vec![IpamConfig {
+                subnet: subnet.to_string(),
+                gateway: subnet.replace(".0/16", ".1"),
+                ip_range: String::new(),
+            }],
+        },
+        containers,
+        options: HashMap::new(),
+        labels: HashMap::new(),
+    }
+}
+
+#[async_trait]
+impl NetworkRepository for FakeNetworkRepository {
+    async fn list(&self) -> Result<Vec<Network>, Box<dyn Error + Send + Sync>> {
+        Ok(vec![
+            network("bridge", "172.17.0.0/16", HashMap::new()),
+            network("backend", "172.18.0.0/16", HashMap::new()),
+        ])
+    }
+
+    /// The fake container is connected to `bridge` only.
+    async fn inspect(&self, id: String) -> Result<Network, Box<dyn Error + Send + Sync>> {
+        match id.as_str() {
+            "bridge-id" => {
+                let mut containers = HashMap::new();
+                containers.insert(
+                    "fake".to_string(),
+                    NetworkEndpoint {
+                        name: "fake".to_string(),
+                        ipv4_address: "172.17.0.2/16".to_string(),
+                        ipv6_address: String::new(),
+                        mac_address: "02:42:ac:11:00:02".to_string(),
+                    },
+                );
+                Ok(network("bridge", "172.17.0.0/16", containers))
+            }
+            "backend-id" => Ok(network("backend", "172.18.0.0/16", HashMap::new())),
+            _ => Err(format!("network {} not found", id).into()),
+        }
+    }
+
+    async fn create(&self, options: CreateNetworkOptions) -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(format!("{}-id", options.name))
+    }
+
+    async fn remove(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.inspect(id).await.map(|_| ())
+    }
+
+    async fn prune(&self) -> Result<NetworkPruneReport, Box<dyn Error + Send + Sync>> {
+        Ok(NetworkPruneReport {
+            networks_deleted: vec!["backend".to_string()],
+        })
+    }
+
+    async fn connect(&self, id: String, _container: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.inspect(id).await.map(|_| ())
+    }
+
+    async fn disconnect(
+        &self,
+        id: String,
+        _container: String,
+        _force: bool,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.inspect(id).await.map(|_| ())
+    }
+}
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::stats::{CpuUsage, StatsRepository, StatsSample, StatsStream};
+use futures_util::stream;
+use std::error::Error;
+
+pub struct FakeStatsRepository {}
+
+impl FakeStatsRepository {
+    pub fn new() -> Self {
+        FakeStatsRepository {}
+    }
+}
+
+impl Default for FakeStatsRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl StatsRepository for FakeStatsRepository {
+    /// Yields two samples one second apart, during which the container used half of a core
+    /// and received 2 KiB.
+    async fn stats(&self, _id: String) -> Result<StatsStream, Box<dyn Error + Send + Sync>> {
+        let read = Utc::now();
+        let sample = |seconds: i64, total_usage: u64, system_usage: u64, network_rx_bytes: u64| StatsSample {
+            read: read + Duration::seconds(seconds),
+            cpu: CpuUsage {
+                total_usage,
+                system_usage,
+                online_cpus: 1,
+            },
+            precpu: CpuUsage {
+                total_usage: 0,
+                system_usage: 0,
+                online_cpus: 1,
+            },
+            memory_usage: 1024,
+            memory_cache: 256,
+            memory_limit: 4096,
+            network_rx_bytes,
+            network_tx_bytes: 0,
+            block_read_bytes: 0,
+            block_write_bytes: 0,
+            pids: 1,
+        };
+        let samples = vec![Ok(sample(0, 0, 0, 1024)), Ok(sample(1, 500, 1000, 3072))];
+        Ok(Box::pin(stream::iter(samples)))
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use domain::size::ByteSize;
+use domain::system::{
+    BuildCacheUsage, ContainerUsage, DiskUsage, ImageUsage, PruneOptions, PruneReport, PruneTarget, SystemRepository,
+};
+use domain::volume::VolumeUsage;
+use std::error::Error;
+
+pub struct FakeSystemRepository {}
+
+impl FakeSystemRepository {
+    pub fn new() -> Self {
+        FakeSystemRepository {}
+    }
+}
+
+impl Default for FakeSystemRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SystemRepository for FakeSystemRepository {
+    /// Two images sharing 100 bytes of layers, one of them used by the running container and the other
+    /// by a stopped one, the volumes of the fake volume repository and a build cache record in use.
+    async fn disk_usage(&self) -> Result<DiskUsage, Box<dyn Error + Send + Sync>> {
+        Ok(DiskUsage {
+            layers_size: ByteSize(1_400),
+            images: vec![
+                ImageUsage {
+                    size: ByteSize(1_000),
+                    shared_size: Some(ByteSize(100)),
+                    containers: Some(1),
+                },
+                ImageUsage {
+                    size: ByteSize(500),
+                    shared_size: Some(ByteSize(100)),
+                    containers: Some(0),
+                },
+            ],
+            containers: vec![
+                ContainerUsage {
+                    size_rw: Some(ByteSize(10)),
+                    running: true,
+                },
+                ContainerUsage {
+                    size_rw: Some(ByteSize(20)),
+                    running: false,
+                },
+            ],
+            volumes: vec![
+                VolumeUsage {
+                    size: Some(ByteSize(4_096)),
+                    ref_count: 1,
+                },
+                VolumeUsage {
+                    size: Some(ByteSize(1_024)),
+                    ref_count: 0,
+                },
+            ],
+            build_cache: vec![BuildCacheUsage {
+                size: ByteSize(300),
+                in_use: true,
+                shared: false,
+            }],
+        })
+    }
+
+    async fn prune(
+        &self,
+        target: PruneTarget,
+        _options: PruneOptions,
+    ) -> Result<PruneReport, Box<dyn Error + Send + Sync>> {
+        let (deleted, space_reclaimed) = match target {
+            PruneTarget::Images => (vec!["sha256:unused".to_string()], 400),
+            PruneTarget::Containers => (vec!["stopped".to_string()], 20),
+            PruneTarget::Volumes => (vec!["cache".to_string()], 1_024),
+            PruneTarget::BuildCache => (vec![], 0),
+        };
+        Ok(PruneReport {
+            deleted,
+            space_reclaimed: ByteSize(space_reclaimed),
+        })
+    }
+}
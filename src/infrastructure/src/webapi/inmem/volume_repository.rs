@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use domain::size::ByteSize;
+use domain::volume::{CreateVolumeOptions, Volume, VolumePruneReport, VolumeRepository, VolumeUsage};
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct FakeVolumeRepository {}
+
+impl FakeVolumeRepository {
+    pub fn new() -> Self {
+        FakeVolumeRepository {}
+    }
+}
+
+impl Default for FakeVolumeRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn volume(name: &str, usage: Option<VolumeUsage>) -> Volume {
+    Volume {
+        name: name.to_string(),
+        driver: "local".to_string(),
+        mountpoint: format!("/var/lib/docker/volumes/{}/_data", name),
+        created: Some(Utc::now()),
+        scope: "local".to_string(),
+        labels: HashMap::new(),
+        options: HashMap::new(),
+        usage,
+    }
+}
+
+#[async_trait]
+impl VolumeRepository for FakeVolumeRepository {
+    /// `data` is mounted by the fake container, `cache` by none.
+    async fn list(&self) -> Result<Vec<Volume>, Box<dyn Error + Send + Sync>> {
+        Ok(vec![
+            volume(
+                "data",
+                Some(VolumeUsage {
+                    size: Some(ByteSize(2048)),
+                    ref_count: 1,
+                }),
+            ),
+            volume(
+                "cache",
+                Some(VolumeUsage {
+                    size: Some(ByteSize(1024)),
+                    ref_count: 0,
+                }),
+            ),
+        ])
+    }
+
+    /// Like the daemon, leaves the usage out.
+    async fn inspect(&self, name: String) -> Result<Volume, Box<dyn Error + Send + Sync>> {
+        self.list()
+            .await?
+            .into_iter()
+            .find(|v| v.name == name)
+            .map(|v| Volume { usage: None, ..v })
+            .ok_or_else(|| format!("no such volume: {}", name).into())
+    }
+
+    async fn create(&self, options: CreateVolumeOptions) -> Result<Volume, Box<dyn Error + Send + Sync>> {
+        Ok(volume(&options.name, None))
+    }
+
+    async fn remove(&self, name: String, force: bool) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if name == "data" && !force {
+            return Err("volume is in use".into());
+        }
+        Ok(())
+    }
+
+    async fn prune(&self) -> Result<VolumePruneReport, Box<dyn Error + Send + Sync>> {
+        Ok(VolumePruneReport {
+            volumes_deleted: vec!["cache".to_string()],
+            space_reclaimed: ByteSize(1024),
+        })
+    }
+}
@@ -1,3 +1,7 @@
+pub mod cache;
 pub mod client;
+pub mod error;
 pub mod inmem;
+pub mod request;
 pub mod rest;
+pub mod stream;
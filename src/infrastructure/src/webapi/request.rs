@@ -0,0 +1,149 @@
+use hyper::{HeaderMap, Method, StatusCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::error::Error;
+
+/// A request to the Docker Engine API, built up with its query, headers and body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+    pub body: RequestBody,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestBody {
+    Empty,
+    Json(Vec<u8>),
+    /// A tar archive, e.g. a build context or files copied into a container.
+    Tar(Vec<u8>),
+}
+
+impl RequestBody {
+    pub fn content_type(&self) -> Option<&'static str> {
+        match self {
+            RequestBody::Empty => None,
+            RequestBody::Json(_) => Some("application/json"),
+            RequestBody::Tar(_) => Some("application/x-tar"),
+        }
+    }
+}
+
+impl ApiRequest {
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: vec![],
+            headers: vec![],
+            body: RequestBody::Empty,
+        }
+    }
+
+    pub fn get(path: impl Into<String>) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    pub fn post(path: impl Into<String>) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    pub fn put(path: impl Into<String>) -> Self {
+        Self::new(Method::PUT, path)
+    }
+
+    pub fn delete(path: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    pub fn head(path: impl Into<String>) -> Self {
+        Self::new(Method::HEAD, path)
+    }
+
+    pub fn query(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.query.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Adds the parameter only when there is a value, for optional arguments.
+    pub fn query_opt(self, key: impl Into<String>, value: Option<impl ToString>) -> Self {
+        match value {
+            Some(value) => self.query(key, value),
+            None => self,
+        }
+    }
+
+    /// Adds Docker's `filters` argument, a JSON object mapping each filter to its accepted values.
+    /// Empty filters are left out as some endpoints reject them.
+    pub fn filters(self, filters: &HashMap<&str, Vec<&str>>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        if filters.is_empty() {
+            return Ok(self);
+        }
+        let json = serde_json::to_string(filters)?;
+        Ok(self.query("filters", json))
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn json(mut self, body: &impl Serialize) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        self.body = RequestBody::Json(serde_json::to_vec(body)?);
+        Ok(self)
+    }
+
+    pub fn tar(mut self, archive: Vec<u8>) -> Self {
+        self.body = RequestBody::Tar(archive);
+        self
+    }
+
+    /// The path with its URL-encoded query string.
+    pub fn path_and_query(&self) -> String {
+        if self.query.is_empty() {
+            return self.path.clone();
+        }
+        let query = form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.query.iter())
+            .finish();
+        format!("{}?{}", self.path, query)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ApiResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiRequest, RequestBody};
+    use std::collections::HashMap;
+
+    #[test]
+    fn encoding_query_and_filters() {
+        let mut filters = HashMap::new();
+        filters.insert("dangling", vec!["true"]);
+        let request = ApiRequest::get("/images/json")
+            .query("all", false)
+            .query_opt("limit", None::<u32>)
+            .filters(&filters)
+            .unwrap();
+        assert_eq!(
+            request.path_and_query(),
+            "/images/json?all=false&filters=%7B%22dangling%22%3A%5B%22true%22%5D%7D"
+        );
+    }
+
+    #[test]
+    fn setting_bodies() {
+        let request = ApiRequest::post("/build").tar(vec![0; 512]);
+        assert_eq!(request.body.content_type(), Some("application/x-tar"));
+        let request = ApiRequest::post("/volumes/create").json(&vec!["x"]).unwrap();
+        assert_eq!(request.body, RequestBody::Json(br#"["x"]"#.to_vec()));
+    }
+}
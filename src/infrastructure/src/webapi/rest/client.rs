@@ -1,36 +1,256 @@
-use crate::webapi::client::Client;
+use crate::config::context_repository::FileContextRepository;
+use crate::webapi::client::{ByteStream, Client};
+use crate::webapi::error::ApiError;
+use crate::webapi::request::{ApiRequest, ApiResponse, RequestBody};
+use crate::webapi::rest::tls;
 use async_trait::async_trait;
+use domain::context::DEFAULT_CONTEXT;
+use domain::error::RepositoryError;
 use futures_util::stream::TryStreamExt;
 use hyper::client::connect::{Connect, HttpConnector};
-use hyper::{self, Body};
+use hyper::upgrade::Upgraded;
+use hyper::{self, header, http::request, Body, Method, Request, Response, StatusCode};
+use hyper_rustls::HttpsConnector;
 use hyperlocal::{UnixClientExt, UnixConnector, Uri};
+use rustls::ClientConfig;
+use std::env;
 use std::error::Error;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use tokio::time;
+
+/// Ports the daemon listens on for TCP endpoints without an explicit port, without and with TLS.
+const DEFAULT_TCP_PORT: u16 = 2375;
+const DEFAULT_TLS_PORT: u16 = 2376;
+pub const DEFAULT_ENDPOINT: &str = "unix:///var/run/docker.sock";
+const DEFAULT_API_VERSION: &str = "1.39";
+/// Stopping a container alone may take its whole stop timeout, 10 seconds by default.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 
 pub struct RestApi<T: Connect> {
     pub client: hyper::Client<T, Body>,
+    /// Scheme and authority every request path is appended to.
     pub url: String,
+    pub api_version: String,
     // 認証情報とかを後で追加する
 }
 
-pub fn new_restapi_client(url: impl Into<String>) -> RestApi<impl Connect + Clone> {
-    let url = url.into();
-    RestApi::<UnixConnector>::new(&url)
+/// Client for daemons listening on a unix socket.
+pub type UnixRestApi = RestApi<UnixConnector>;
+/// Client for daemons listening on TCP, with or without TLS.
+pub type TcpRestApi = RestApi<HttpsConnector<HttpConnector>>;
+
+/// Where the Docker daemon listens, e.g. `unix:///var/run/docker.sock` or `tcp://127.0.0.1:2375`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Endpoint {
+    Unix(String),
+    /// The port is left out when the endpoint doesn't give one, as its default depends on whether TLS
+    /// is used, which the certificates found later may still decide. IPv6 hosts keep their brackets.
+    Tcp {
+        host: String,
+        port: Option<u16>,
+        tls: bool,
+    },
+}
+
+impl Endpoint {
+    /// The host and port of a TCP endpoint, the port defaulting to 2376 with TLS and to 2375 without.
+    pub fn authority(&self) -> Option<String> {
+        match self {
+            Endpoint::Unix(_) => None,
+            Endpoint::Tcp { host, port, tls } => {
+                let default = if *tls { DEFAULT_TLS_PORT } else { DEFAULT_TCP_PORT };
+                Some(format!("{}:{}", host, port.unwrap_or(default)))
+            }
+        }
+    }
+}
+
+impl FromStr for Endpoint {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (scheme, rest) = s
+            .split_once("://")
+            .ok_or_else(|| format!("endpoint {} has no scheme", s))?;
+        if scheme == "unix" {
+            if rest.is_empty() {
+                return Err(format!("endpoint {} has no socket path", s).into());
+            }
+            return Ok(Endpoint::Unix(rest.to_string()));
+        }
+
+        let tls = match scheme {
+            "tcp" | "http" => false,
+            "https" => true,
+            _ => return Err(format!("unsupported endpoint scheme {}", scheme).into()),
+        };
+        let invalid = || format!("endpoint {} must be a host and an optional port", s);
+        let authority = rest.trim_end_matches('/');
+        // An IPv6 address is bracketed so that its colons aren't taken for the port separator.
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => {
+                let (address, port) = bracketed.split_once(']').ok_or_else(invalid)?;
+                (format!("[{}]", address), port)
+            }
+            None => match authority.find(':') {
+                Some(i) => (authority[..i].to_string(), &authority[i..]),
+                None => (authority.to_string(), ""),
+            },
+        };
+        if host.is_empty() || host == "[]" || host.contains('/') {
+            return Err(invalid().into());
+        }
+        let port = match port {
+            "" => None,
+            port => Some(
+                port.strip_prefix(':')
+                    .and_then(|p| p.parse::<u16>().ok())
+                    .ok_or_else(invalid)?,
+            ),
+        };
+        Ok(Endpoint::Tcp { host, port, tls })
+    }
+}
+
+/// Everything needed to reach a daemon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionConfig {
+    pub endpoint: Endpoint,
+    /// Sent as the `/v{api_version}` path prefix; an empty version uses the daemon's own.
+    pub api_version: String,
+    pub tls: Option<TlsConfig>,
+    /// Docker context the connection comes from, `default` when it isn't a stored one.
+    pub context: String,
+}
+
+/// Client certificates for daemons protected with mutual TLS.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsConfig {
+    /// Directory holding `ca.pem`, `cert.pem` and `key.pem`.
+    pub cert_path: PathBuf,
+    /// Whether the daemon's certificate is checked against `ca.pem`.
+    pub verify: bool,
+}
+
+impl ConnectionConfig {
+    /// Resolves the connection the way the `docker` CLI does: the given endpoint first, then the given
+    /// context, `DOCKER_HOST`, `DOCKER_CONTEXT` and the current context of the docker config, then the
+    /// local socket. `DOCKER_API_VERSION`, `DOCKER_CERT_PATH` and `DOCKER_TLS_VERIFY` fill in the rest.
+    pub fn resolve(
+        endpoint: Option<&str>,
+        api_version: Option<&str>,
+        context: Option<&str>,
+        contexts: &FileContextRepository,
+    ) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        resolve_with(endpoint, api_version, context, contexts, |key| env::var(key).ok())
+    }
+}
+
+fn resolve_with(
+    endpoint: Option<&str>,
+    api_version: Option<&str>,
+    context: Option<&str>,
+    contexts: &FileContextRepository,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<ConnectionConfig, Box<dyn Error + Send + Sync>> {
+    // Like the docker CLI, an empty variable counts as unset.
+    let var = |key: &str| var(key).filter(|v| !v.is_empty());
+
+    let api_version = api_version
+        .map(|v| v.to_string())
+        .or_else(|| var("DOCKER_API_VERSION"))
+        .unwrap_or_else(|| DEFAULT_API_VERSION.to_string());
+
+    let context = match (endpoint, context) {
+        (Some(_), _) => None,
+        (None, Some(context)) => Some(context.to_string()),
+        (None, None) if var("DOCKER_HOST").is_some() => None,
+        (None, None) => var("DOCKER_CONTEXT").or_else(|| contexts.current()),
+    };
+    if let Some(context) = context.filter(|c| c != DEFAULT_CONTEXT) {
+        let stored = contexts.endpoint(&context)?;
+        return Ok(ConnectionConfig {
+            endpoint: stored.endpoint,
+            api_version,
+            tls: stored.tls,
+            context,
+        });
+    }
+
+    let mut endpoint: Endpoint = match endpoint {
+        Some(endpoint) => endpoint.parse()?,
+        None => var("DOCKER_HOST").as_deref().unwrap_or(DEFAULT_ENDPOINT).parse()?,
+    };
+    let verify = var("DOCKER_TLS_VERIFY").is_some();
+    let cert_path = var("DOCKER_CERT_PATH").map(PathBuf::from).or_else(|| {
+        if verify {
+            var("HOME").map(|home| PathBuf::from(home).join(".docker"))
+        } else {
+            None
+        }
+    });
+    let tls = cert_path.map(|cert_path| TlsConfig { cert_path, verify });
+    if let (Endpoint::Tcp { tls: use_tls, .. }, Some(_)) = (&mut endpoint, &tls) {
+        *use_tls = true;
+    }
+
+    Ok(ConnectionConfig {
+        endpoint,
+        api_version,
+        tls,
+        context: DEFAULT_CONTEXT.to_string(),
+    })
 }
 
 impl RestApi<UnixConnector> {
-    pub fn new(unix_socket_path: &str) -> RestApi<UnixConnector> {
+    pub fn new(unix_socket_path: &str, api_version: &str) -> RestApi<UnixConnector> {
         let client = hyper::Client::unix();
-        let url = unix_socket_path.to_string();
-        RestApi { client, url }
+        let uri: hyper::Uri = Uri::new(unix_socket_path, "/").into();
+        let url = uri.to_string().trim_end_matches('/').to_string();
+        RestApi {
+            client,
+            url,
+            api_version: api_version.to_string(),
+        }
     }
 }
 
-impl RestApi<HttpConnector> {
-    #[allow(dead_code)]
-    pub fn new(domain: &str) -> RestApi<HttpConnector> {
-        let client = hyper::Client::new();
-        let url = domain.to_string();
-        RestApi { client, url }
+impl RestApi<HttpsConnector<HttpConnector>> {
+    /// Connects over plain HTTP, or over HTTPS trusting the system's root certificates.
+    pub fn new(authority: &str, tls: bool, api_version: &str) -> RestApi<HttpsConnector<HttpConnector>> {
+        let mut config = ClientConfig::new();
+        // Hijacked connections such as exec need HTTP/1.1.
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+        if let Ok(store) = rustls_native_certs::load_native_certs() {
+            config.root_store = store;
+        }
+        let scheme = if tls { "https" } else { "http" };
+        Self::build(config, format!("{}://{}", scheme, authority), api_version)
+    }
+
+    /// Connects over HTTPS authenticating with the client certificate in the cert directory.
+    pub fn new_with_tls(
+        authority: &str,
+        tls: &TlsConfig,
+        api_version: &str,
+    ) -> Result<RestApi<HttpsConnector<HttpConnector>>, Box<dyn Error + Send + Sync>> {
+        let config = tls::client_config(tls)?;
+        Ok(Self::build(config, format!("https://{}", authority), api_version))
+    }
+
+    fn build(config: ClientConfig, url: String, api_version: &str) -> RestApi<HttpsConnector<HttpConnector>> {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(Some(CONNECT_TIMEOUT));
+        let client = hyper::Client::builder().build(HttpsConnector::from((http, config)));
+        RestApi {
+            client,
+            url,
+            api_version: api_version.to_string(),
+        }
     }
 }
 
@@ -39,15 +259,215 @@ impl<T> Client for RestApi<T>
 where
     T: Connect + Clone + Send + Sync + 'static,
 {
-    async fn get(&self, path: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
-        let uri = Uri::new(&self.url, path).into();
-        let response_body = self.client.get(uri).await?.into_body();
-        let bytes = response_body
-            .try_fold(Vec::default(), |mut buf, bytes| async {
-                buf.extend(bytes);
-                Ok(buf)
+    async fn request(&self, request: ApiRequest) -> Result<ApiResponse, Box<dyn Error + Send + Sync>> {
+        let request = self.to_hyper_request(request)?;
+        let response = self.dispatch(request).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = read_body(response.into_body()).await?;
+        // 304 is returned when the container is already in the requested state.
+        if !status.is_success() && status != StatusCode::NOT_MODIFIED {
+            return Err(RepositoryError::from(ApiError::from_response(status, &body)).into());
+        }
+        Ok(ApiResponse { status, headers, body })
+    }
+
+    async fn stream(&self, request: ApiRequest) -> Result<ByteStream, Box<dyn Error + Send + Sync>> {
+        let request = self.to_hyper_request(request)?;
+        let response = self.dispatch(request).await?;
+        let status = response.status();
+        if !status.is_success() {
+            let bytes = read_body(response.into_body()).await?;
+            return Err(RepositoryError::from(ApiError::from_response(status, &bytes)).into());
+        }
+        let stream = response
+            .into_body()
+            .map_ok(|bytes| bytes.to_vec())
+            .map_err(|e| RepositoryError::from(ApiError::from_transport(Box::new(e))).into());
+        Ok(Box::pin(stream))
+    }
+
+    async fn upgrade(&self, path: &str, body: Vec<u8>) -> Result<Upgraded, Box<dyn Error + Send + Sync>> {
+        let request = self
+            .post_request(path)?
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::CONNECTION, "Upgrade")
+            .header(header::UPGRADE, "tcp")
+            .body(Body::from(body))?;
+        let response = self.dispatch(request).await?;
+        let status = response.status();
+        if status != StatusCode::SWITCHING_PROTOCOLS {
+            let bytes = read_body(response.into_body()).await?;
+            return Err(RepositoryError::from(ApiError::from_response(status, &bytes)).into());
+        }
+        let upgraded = response
+            .into_body()
+            .on_upgrade()
+            .await
+            .map_err(|e| RepositoryError::from(ApiError::from_transport(Box::new(e))))?;
+        Ok(upgraded)
+    }
+}
+
+impl<T> RestApi<T>
+where
+    T: Connect + Clone + Send + Sync + 'static,
+{
+    fn uri(&self, path: &str) -> Result<hyper::Uri, Box<dyn Error + Send + Sync>> {
+        let uri = if self.api_version.is_empty() {
+            format!("{}{}", self.url, path)
+        } else {
+            format!("{}/v{}{}", self.url, self.api_version, path)
+        };
+        Ok(uri.parse()?)
+    }
+
+    fn to_hyper_request(&self, request: ApiRequest) -> Result<Request<Body>, Box<dyn Error + Send + Sync>> {
+        let mut builder = Request::builder()
+            .method(request.method.clone())
+            .uri(self.uri(&request.path_and_query())?);
+        if let Some(content_type) = request.body.content_type() {
+            builder = builder.header(header::CONTENT_TYPE, content_type);
+        }
+        for (name, value) in request.headers.iter() {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        let body = match request.body {
+            RequestBody::Empty => Body::empty(),
+            RequestBody::Json(bytes) | RequestBody::Tar(bytes) => Body::from(bytes),
+        };
+        Ok(builder.body(body)?)
+    }
+
+    fn post_request(&self, path: &str) -> Result<request::Builder, Box<dyn Error + Send + Sync>> {
+        Ok(Request::builder().method(Method::POST).uri(self.uri(path)?))
+    }
+
+    /// Waits for the response headers at most `REQUEST_TIMEOUT`; the body may take longer.
+    async fn dispatch(&self, request: Request<Body>) -> Result<Response<Body>, RepositoryError> {
+        match time::timeout(REQUEST_TIMEOUT, self.client.request(request)).await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(e)) => Err(ApiError::from_transport(Box::new(e)).into()),
+            Err(_) => Err(ApiError::Timeout.into()),
+        }
+    }
+}
+
+async fn read_body(body: Body) -> Result<Vec<u8>, RepositoryError> {
+    let bytes = body
+        .try_fold(Vec::default(), |mut buf, bytes| async {
+            buf.extend(bytes);
+            Ok(buf)
+        })
+        .await
+        .map_err(|e| RepositoryError::from(ApiError::from_transport(Box::new(e))))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_with, Endpoint, RestApi, TlsConfig};
+    use crate::config::context_repository::FileContextRepository;
+    use hyperlocal::UnixConnector;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    #[test]
+    fn parsing_endpoints() {
+        let tcp = |host: &str, port: Option<u16>, tls: bool| Endpoint::Tcp {
+            host: host.to_string(),
+            port,
+            tls,
+        };
+        assert_eq!(
+            "unix:///var/run/docker.sock".parse::<Endpoint>().unwrap(),
+            Endpoint::Unix("/var/run/docker.sock".to_string())
+        );
+        assert_eq!(
+            "tcp://10.0.0.1".parse::<Endpoint>().unwrap(),
+            tcp("10.0.0.1", None, false)
+        );
+        assert_eq!(
+            "https://docker.example.com:2376/".parse::<Endpoint>().unwrap(),
+            tcp("docker.example.com", Some(2376), true)
+        );
+        assert_eq!(
+            "tcp://[::1]:2375".parse::<Endpoint>().unwrap(),
+            tcp("[::1]", Some(2375), false)
+        );
+        assert_eq!(
+            "tcp://[fe80::1]".parse::<Endpoint>().unwrap(),
+            tcp("[fe80::1]", None, false)
+        );
+        assert!("/var/run/docker.sock".parse::<Endpoint>().is_err());
+        assert!("ssh://user@host".parse::<Endpoint>().is_err());
+        assert!("tcp://::1".parse::<Endpoint>().is_err());
+        assert!("tcp://[::1".parse::<Endpoint>().is_err());
+        assert!("tcp://host:port".parse::<Endpoint>().is_err());
+        assert!("tcp://host/path".parse::<Endpoint>().is_err());
+    }
+
+    #[test]
+    fn defaulting_the_port_by_tls() {
+        let authority = |s: &str| s.parse::<Endpoint>().unwrap().authority();
+        assert_eq!(authority("tcp://10.0.0.1"), Some("10.0.0.1:2375".to_string()));
+        assert_eq!(
+            authority("https://docker.example.com"),
+            Some("docker.example.com:2376".to_string())
+        );
+        assert_eq!(
+            authority("https://docker.example.com:443"),
+            Some("docker.example.com:443".to_string())
+        );
+        assert_eq!(authority("tcp://[::1]"), Some("[::1]:2375".to_string()));
+        assert_eq!(authority("unix:///var/run/docker.sock"), None);
+    }
+
+    #[test]
+    fn prefixing_paths_with_api_version() {
+        let client = RestApi::<UnixConnector>::new("/var/run/docker.sock", "1.39");
+        let uri = client.uri("/containers/json").unwrap();
+        assert_eq!(uri.path(), "/v1.39/containers/json");
+    }
+
+    #[test]
+    fn resolving_connection_from_environment() {
+        let vars: HashMap<&str, &str> = vec![
+            ("DOCKER_HOST", "tcp://docker.example.com:2376"),
+            ("DOCKER_API_VERSION", "1.41"),
+            ("DOCKER_CERT_PATH", "/certs"),
+            ("DOCKER_TLS_VERIFY", "1"),
+        ]
+        .into_iter()
+        .collect();
+        let var = |key: &str| vars.get(key).map(|v| v.to_string());
+        let contexts = FileContextRepository::new("/nonexistent", "unix:///var/run/docker.sock");
+
+        let config = resolve_with(None, None, None, &contexts, var).unwrap();
+        assert_eq!(
+            config.endpoint,
+            Endpoint::Tcp {
+                host: "docker.example.com".to_string(),
+                port: Some(2376),
+                tls: true
+            }
+        );
+        assert_eq!(config.api_version, "1.41");
+        assert_eq!(
+            config.tls,
+            Some(TlsConfig {
+                cert_path: PathBuf::from("/certs"),
+                verify: true
             })
-            .await?;
-        Ok(bytes)
+        );
+
+        // Command line options win over the environment.
+        let config = resolve_with(Some("unix:///tmp/docker.sock"), Some("1.39"), None, &contexts, var).unwrap();
+        assert_eq!(config.endpoint, Endpoint::Unix("/tmp/docker.sock".to_string()));
+        assert_eq!(config.api_version, "1.39");
+
+        // The certificates switch to TLS, and with it to the TLS port.
+        let config = resolve_with(Some("tcp://docker.example.com"), None, None, &contexts, var).unwrap();
+        assert_eq!(config.endpoint.authority(), Some("docker.example.com:2376".to_string()));
     }
 }
@@ -1,6 +1,11 @@
 use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
 use async_trait::async_trait;
-use domain::container::{ContainerRepository, ContainerSummary};
+use domain::container::{
+    Container, ContainerConfig, ContainerRepository, ContainerState, ContainerSummary, EndpointSettings, Health,
+    HealthCheckResult, HostConfig, Mount, NetworkSettings, Port, RestartPolicy,
+};
+use std::collections::HashMap;
 use std::error::Error;
 
 pub struct RestfulApiContainerRepository<'a, T: Client> {
@@ -11,6 +16,39 @@ impl<'a, T: Client> RestfulApiContainerRepository<'a, T> {
     pub fn new(client: &'a T) -> Self {
         Self { client }
     }
+
+    async fn list_with(&self, request: ApiRequest) -> Result<Vec<ContainerSummary>, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.request(request).await?.body;
+
+        let containers: Vec<types::ContainerSummary> = serde_json::from_slice(&bytes)?;
+        let items = containers
+            .into_iter()
+            .map(|v| {
+                let ports = v
+                    .ports
+                    .into_iter()
+                    .map(|p| Port {
+                        ip: p.ip,
+                        private_port: p.private_port,
+                        public_port: p.public_port,
+                        protocol: p.type_field,
+                    })
+                    .collect();
+                ContainerSummary::from_repository(
+                    v.id,
+                    v.names,
+                    v.image,
+                    v.command,
+                    v.created.unwrap(),
+                    v.state,
+                    v.status,
+                    ports,
+                    v.labels.unwrap_or_default(),
+                )
+            })
+            .collect();
+        Ok(items)
+    }
 }
 
 #[async_trait]
@@ -19,17 +57,177 @@ where
     T: Client + Send + Sync + 'static,
 {
     async fn list(&self) -> Result<Vec<ContainerSummary>, Box<dyn Error + Send + Sync>> {
-        let bytes = self.client.get("/containers/json").await?;
+        // Stopped containers are listed too so that they can be started again.
+        let request = ApiRequest::get("/containers/json").query("all", true);
+        self.list_with(request).await
+    }
 
-        let containers: Vec<types::ContainerSummary> = serde_json::from_slice(&bytes)?;
-        let items = containers
-            .into_iter()
-            .map(|v| ContainerSummary::from_repository(v.id, v.names, v.image, v.created.unwrap(), v.status))
-            .collect();
-        Ok(items)
+    async fn list_by_volume(&self, volume: String) -> Result<Vec<ContainerSummary>, Box<dyn Error + Send + Sync>> {
+        let mut filters = HashMap::new();
+        filters.insert("volume", vec![volume.as_str()]);
+        let request = ApiRequest::get("/containers/json")
+            .query("all", true)
+            .filters(&filters)?;
+        self.list_with(request).await
+    }
+
+    async fn inspect(&self, id: String) -> Result<Container, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.get(&format!("/containers/{}/json", id)).await?;
+        let detail: types::ContainerInspect = serde_json::from_slice(&bytes)?;
+
+        let state = detail.state;
+        let config = detail.config;
+        let host_config = detail.host_config;
+        Ok(Container {
+            id: detail.id,
+            name: detail.name.trim_start_matches('/').to_string(),
+            image: config.image,
+            created: detail.created,
+            state: ContainerState {
+                status: state.status,
+                running: state.running,
+                paused: state.paused,
+                restarting: state.restarting,
+                oom_killed: state.oom_killed,
+                dead: state.dead,
+                pid: state.pid,
+                exit_code: state.exit_code,
+                error: state.error,
+                started_at: state.started_at,
+                finished_at: state.finished_at,
+                health: state.health.map(|h| Health {
+                    status: h.status,
+                    failing_streak: h.failing_streak,
+                    log: h
+                        .log
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|l| HealthCheckResult {
+                            start: l.start,
+                            end: l.end,
+                            exit_code: l.exit_code,
+                            output: l.output,
+                        })
+                        .collect(),
+                }),
+            },
+            config: ContainerConfig {
+                hostname: config.hostname,
+                user: config.user,
+                env: config.env.unwrap_or_default(),
+                cmd: config.cmd.unwrap_or_default(),
+                entrypoint: config.entrypoint.unwrap_or_default(),
+                working_dir: config.working_dir,
+                labels: config.labels.unwrap_or_default(),
+                tty: config.tty,
+                open_stdin: config.open_stdin,
+                exposed_ports: config.exposed_ports.unwrap_or_default().into_keys().collect(),
+            },
+            host_config: HostConfig {
+                network_mode: host_config.network_mode,
+                privileged: host_config.privileged,
+                binds: host_config.binds.unwrap_or_default(),
+                restart_policy: RestartPolicy {
+                    name: host_config.restart_policy.name,
+                    maximum_retry_count: host_config.restart_policy.maximum_retry_count,
+                },
+            },
+            mounts: detail.mounts.into_iter().map(to_mount).collect(),
+            network_settings: NetworkSettings {
+                ports: to_ports(detail.network_settings.ports.unwrap_or_default()),
+                networks: detail
+                    .network_settings
+                    .networks
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(name, e)| {
+                        let endpoint = EndpointSettings {
+                            network_id: e.network_id,
+                            ip_address: e.ip_address,
+                            gateway: e.gateway,
+                            mac_address: e.mac_address,
+                            aliases: e.aliases.unwrap_or_default(),
+                        };
+                        (name, endpoint)
+                    })
+                    .collect(),
+            },
+        })
+    }
+
+    async fn start(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.client.post(&format!("/containers/{}/start", id)).await?;
+        Ok(())
+    }
+
+    async fn stop(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.client.post(&format!("/containers/{}/stop", id)).await?;
+        Ok(())
+    }
+
+    async fn restart(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.client.post(&format!("/containers/{}/restart", id)).await?;
+        Ok(())
+    }
+
+    async fn kill(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.client.post(&format!("/containers/{}/kill", id)).await?;
+        Ok(())
+    }
+
+    async fn pause(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.client.post(&format!("/containers/{}/pause", id)).await?;
+        Ok(())
+    }
+
+    async fn unpause(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.client.post(&format!("/containers/{}/unpause", id)).await?;
+        Ok(())
     }
 }
 
+fn to_mount(mount: types::Mount) -> Mount {
+    Mount {
+        mount_type: mount.type_field,
+        name: mount.name,
+        source: mount.source,
+        destination: mount.destination,
+        driver: mount.driver,
+        mode: mount.mode,
+        rw: mount.rw,
+    }
+}
+
+/// Flattens the `"80/tcp": [{"HostIp": .., "HostPort": ..}]` map into one `Port` per binding.
+fn to_ports(bindings: HashMap<String, Option<Vec<types::PortBinding>>>) -> Vec<Port> {
+    let mut ports = Vec::new();
+    for (key, bindings) in bindings.into_iter() {
+        let mut parts = key.splitn(2, '/');
+        let private_port = parts.next().and_then(|p| p.parse().ok()).unwrap_or_default();
+        let protocol = parts.next().unwrap_or("tcp").to_string();
+        match bindings {
+            Some(bindings) if !bindings.is_empty() => {
+                for binding in bindings.into_iter() {
+                    ports.push(Port {
+                        ip: Some(binding.host_ip).filter(|ip| !ip.is_empty()),
+                        private_port,
+                        public_port: binding.host_port.parse().ok(),
+                        protocol: protocol.clone(),
+                    });
+                }
+            }
+            _ => ports.push(Port {
+                ip: None,
+                private_port,
+                public_port: None,
+                protocol,
+            }),
+        }
+    }
+    ports.sort_by_key(|p| (p.private_port, p.public_port));
+    ports
+}
+
 mod types {
     use crate::shared::date_format;
     use chrono::{DateTime, Utc};
@@ -59,9 +257,9 @@ mod types {
         pub ports: Vec<Port>,
         #[serde(rename = "Labels")]
         pub labels: Option<HashMap<String, String>>,
-        #[serde(rename = "SizeRw")]
+        #[serde(rename = "SizeRw", default)]
         pub size_rw: i64,
-        #[serde(rename = "SizeRootFs")]
+        #[serde(rename = "SizeRootFs", default)]
         pub size_root_fs: i64,
         #[serde(rename = "HostConfig")]
         pub host_config: HostConfig,
@@ -74,10 +272,12 @@ mod types {
     #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
     #[serde(rename_all = "camelCase")]
     pub struct Port {
+        #[serde(rename = "IP")]
+        pub ip: Option<String>,
         #[serde(rename = "PrivatePort")]
-        pub private_port: i64,
+        pub private_port: u16,
         #[serde(rename = "PublicPort")]
-        pub public_port: i64,
+        pub public_port: Option<u16>,
         #[serde(rename = "Type")]
         pub type_field: String,
     }
@@ -99,7 +299,7 @@ mod types {
     #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
     #[serde(rename_all = "camelCase")]
     pub struct Networks {
-        pub bridge: Bridge,
+        pub bridge: Option<Bridge>,
     }
 
     #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
@@ -128,14 +328,16 @@ mod types {
     #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
     #[serde(rename_all = "camelCase")]
     pub struct Mount {
+        #[serde(rename = "Type")]
+        pub type_field: String,
         #[serde(rename = "Name")]
-        pub name: String,
+        pub name: Option<String>,
         #[serde(rename = "Source")]
         pub source: String,
         #[serde(rename = "Destination")]
         pub destination: String,
         #[serde(rename = "Driver")]
-        pub driver: String,
+        pub driver: Option<String>,
         #[serde(rename = "Mode")]
         pub mode: String,
         #[serde(rename = "RW")]
@@ -143,4 +345,170 @@ mod types {
         #[serde(rename = "Propagation")]
         pub propagation: String,
     }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ContainerInspect {
+        #[serde(rename = "Id")]
+        pub id: String,
+        #[serde(rename = "Name")]
+        pub name: String,
+        #[serde(rename = "Created")]
+        pub created: DateTime<Utc>,
+        #[serde(rename = "Path")]
+        pub path: String,
+        #[serde(rename = "Args")]
+        pub args: Vec<String>,
+        #[serde(rename = "State")]
+        pub state: State,
+        #[serde(rename = "Image")]
+        pub image: String,
+        #[serde(rename = "RestartCount")]
+        pub restart_count: i64,
+        #[serde(rename = "Driver")]
+        pub driver: String,
+        #[serde(rename = "Config")]
+        pub config: Config,
+        #[serde(rename = "HostConfig")]
+        pub host_config: InspectHostConfig,
+        #[serde(rename = "Mounts")]
+        pub mounts: Vec<Mount>,
+        #[serde(rename = "NetworkSettings")]
+        pub network_settings: InspectNetworkSettings,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct State {
+        #[serde(rename = "Status")]
+        pub status: String,
+        #[serde(rename = "Running")]
+        pub running: bool,
+        #[serde(rename = "Paused")]
+        pub paused: bool,
+        #[serde(rename = "Restarting")]
+        pub restarting: bool,
+        #[serde(rename = "OOMKilled")]
+        pub oom_killed: bool,
+        #[serde(rename = "Dead")]
+        pub dead: bool,
+        #[serde(rename = "Pid")]
+        pub pid: i64,
+        #[serde(rename = "ExitCode")]
+        pub exit_code: i64,
+        #[serde(rename = "Error")]
+        pub error: String,
+        #[serde(rename = "StartedAt")]
+        pub started_at: String,
+        #[serde(rename = "FinishedAt")]
+        pub finished_at: String,
+        #[serde(rename = "Health")]
+        pub health: Option<Health>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Health {
+        #[serde(rename = "Status")]
+        pub status: String,
+        #[serde(rename = "FailingStreak")]
+        pub failing_streak: i64,
+        #[serde(rename = "Log")]
+        pub log: Option<Vec<HealthcheckResult>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct HealthcheckResult {
+        #[serde(rename = "Start")]
+        pub start: String,
+        #[serde(rename = "End")]
+        pub end: String,
+        #[serde(rename = "ExitCode")]
+        pub exit_code: i64,
+        #[serde(rename = "Output")]
+        pub output: String,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Config {
+        #[serde(rename = "Hostname")]
+        pub hostname: String,
+        #[serde(rename = "User")]
+        pub user: String,
+        #[serde(rename = "Tty")]
+        pub tty: bool,
+        #[serde(rename = "OpenStdin")]
+        pub open_stdin: bool,
+        #[serde(rename = "ExposedPorts")]
+        pub exposed_ports: Option<HashMap<String, ::serde_json::Value>>,
+        #[serde(rename = "Env")]
+        pub env: Option<Vec<String>>,
+        #[serde(rename = "Cmd")]
+        pub cmd: Option<Vec<String>>,
+        #[serde(rename = "Entrypoint")]
+        pub entrypoint: Option<Vec<String>>,
+        #[serde(rename = "Image")]
+        pub image: String,
+        #[serde(rename = "WorkingDir")]
+        pub working_dir: String,
+        #[serde(rename = "Labels")]
+        pub labels: Option<HashMap<String, String>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct InspectHostConfig {
+        #[serde(rename = "NetworkMode")]
+        pub network_mode: String,
+        #[serde(rename = "Privileged")]
+        pub privileged: bool,
+        #[serde(rename = "Binds")]
+        pub binds: Option<Vec<String>>,
+        #[serde(rename = "RestartPolicy")]
+        pub restart_policy: RestartPolicy,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct RestartPolicy {
+        #[serde(rename = "Name")]
+        pub name: String,
+        #[serde(rename = "MaximumRetryCount")]
+        pub maximum_retry_count: i64,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct InspectNetworkSettings {
+        #[serde(rename = "Ports")]
+        pub ports: Option<HashMap<String, Option<Vec<PortBinding>>>>,
+        #[serde(rename = "Networks")]
+        pub networks: Option<HashMap<String, EndpointSettings>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PortBinding {
+        #[serde(rename = "HostIp")]
+        pub host_ip: String,
+        #[serde(rename = "HostPort")]
+        pub host_port: String,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct EndpointSettings {
+        #[serde(rename = "NetworkID")]
+        pub network_id: String,
+        #[serde(rename = "Aliases")]
+        pub aliases: Option<Vec<String>>,
+        #[serde(rename = "Gateway")]
+        pub gateway: String,
+        #[serde(rename = "IPAddress")]
+        pub ip_address: String,
+        #[serde(rename = "MacAddress")]
+        pub mac_address: String,
+    }
 }
@@ -0,0 +1,80 @@
+use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use crate::webapi::stream::json_lines;
+use async_trait::async_trait;
+use chrono::{DateTime, TimeZone, Utc};
+use domain::event::{EventKind, EventRepository, EventStream, SystemEvent};
+use futures_util::stream::StreamExt;
+use std::error::Error;
+
+pub struct RestfulApiEventRepository<'a, T: Client> {
+    client: &'a T,
+}
+
+impl<'a, T: Client> RestfulApiEventRepository<'a, T> {
+    pub fn new(client: &'a T) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<'a, T> EventRepository for RestfulApiEventRepository<'a, T>
+where
+    T: Client + Send + Sync + 'static,
+{
+    async fn events(&self, since: Option<DateTime<Utc>>) -> Result<EventStream, Box<dyn Error + Send + Sync>> {
+        let request = ApiRequest::get("/events").query_opt("since", since.map(|v| v.timestamp()));
+        let chunks = self.client.stream(request).await?;
+        let events = json_lines::<types::Message>(chunks).map(|message| message.map(to_event));
+        Ok(Box::pin(events))
+    }
+}
+
+fn to_event(message: types::Message) -> SystemEvent {
+    let kind = match message.kind.as_str() {
+        "container" => EventKind::Container,
+        "image" => EventKind::Image,
+        "volume" => EventKind::Volume,
+        "network" => EventKind::Network,
+        "daemon" => EventKind::Daemon,
+        other => EventKind::Other(other.to_string()),
+    };
+    let time = match message.time_nano {
+        Some(nanos) => Utc.timestamp_nanos(nanos),
+        None => Utc.timestamp_opt(message.time, 0).single().unwrap_or_else(Utc::now),
+    };
+    SystemEvent {
+        kind,
+        action: message.action,
+        actor_id: message.actor.id,
+        attributes: message.actor.attributes,
+        time,
+    }
+}
+
+mod types {
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Message {
+        #[serde(rename = "Type")]
+        pub kind: String,
+        #[serde(rename = "Action")]
+        pub action: String,
+        #[serde(rename = "Actor", default)]
+        pub actor: Actor,
+        #[serde(rename = "time", default)]
+        pub time: i64,
+        #[serde(rename = "timeNano")]
+        pub time_nano: Option<i64>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Actor {
+        #[serde(rename = "ID")]
+        pub id: String,
+        #[serde(rename = "Attributes", default)]
+        pub attributes: HashMap<String, String>,
+    }
+}
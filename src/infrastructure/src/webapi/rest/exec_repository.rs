@@ -0,0 +1,218 @@
+use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use async_trait::async_trait;
+use domain::exec::{ExecInput, ExecRepository, ExecSession};
+use futures_util::stream;
+use hyper::upgrade::Upgraded;
+use std::error::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt, ReadHalf};
+use tokio::sync::mpsc;
+
+const READ_BUFFER_SIZE: usize = 4096;
+
+pub struct RestfulApiExecRepository<'a, T: Client> {
+    client: &'a T,
+}
+
+impl<'a, T: Client> RestfulApiExecRepository<'a, T> {
+    pub fn new(client: &'a T) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<'a, T> ExecRepository for RestfulApiExecRepository<'a, T>
+where
+    T: Client + Send + Sync + 'static,
+{
+    async fn create(&self, container_id: String, cmd: Vec<String>) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let config = types::ExecConfig {
+            attach_stdin: true,
+            attach_stdout: true,
+            attach_stderr: true,
+            tty: true,
+            cmd,
+        };
+        let request = ApiRequest::post(format!("/containers/{}/exec", container_id)).json(&config)?;
+        let bytes = self.client.request(request).await?.body;
+        let response: types::IdResponse = serde_json::from_slice(&bytes)?;
+        Ok(response.id)
+    }
+
+    async fn start(&self, exec_id: String) -> Result<ExecSession, Box<dyn Error + Send + Sync>> {
+        let config = types::ExecStartConfig {
+            detach: false,
+            tty: true,
+        };
+        let body = serde_json::to_vec(&config)?;
+        let upgraded = self.client.upgrade(&format!("/exec/{}/start", exec_id), body).await?;
+        let (reader, mut writer) = tokio::io::split(upgraded);
+
+        // Keystrokes are queued so that the UI never waits on the connection.
+        let (tx, mut rx) = mpsc::unbounded_channel::<Vec<u8>>();
+        tokio::spawn(async move {
+            while let Some(bytes) = rx.recv().await {
+                if writer.write_all(&bytes).await.is_err() {
+                    break;
+                }
+            }
+            let _ = writer.shutdown().await;
+        });
+
+        Ok(ExecSession {
+            id: exec_id,
+            output: Box::pin(read_output(reader)),
+            input: Box::new(UpgradedInput { tx }),
+        })
+    }
+
+    async fn resize(&self, exec_id: String, width: u16, height: u16) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let request = ApiRequest::post(format!("/exec/{}/resize", exec_id))
+            .query("h", height)
+            .query("w", width);
+        self.client.request(request).await?;
+        Ok(())
+    }
+}
+
+struct UpgradedInput {
+    tx: mpsc::UnboundedSender<Vec<u8>>,
+}
+
+impl ExecInput for UpgradedInput {
+    fn write(&mut self, bytes: Vec<u8>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.tx
+            .send(bytes)
+            .map_err(|_| "the exec session has been closed".into())
+    }
+}
+
+/// With a TTY the daemon sends the process output as is, without the stdout/stderr framing.
+fn read_output(
+    reader: ReadHalf<Upgraded>,
+) -> impl stream::Stream<Item = Result<Vec<u8>, Box<dyn Error + Send + Sync>>> {
+    stream::unfold(Some(reader), |reader| async move {
+        let mut reader = reader?;
+        let mut buf = vec![0; READ_BUFFER_SIZE];
+        match reader.read(&mut buf).await {
+            Ok(0) => None,
+            Ok(n) => {
+                buf.truncate(n);
+                Some((Ok(buf), Some(reader)))
+            }
+            Err(e) => Some((Err(e.into()), None)),
+        }
+    })
+}
+
+mod types {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+    pub struct ExecConfig {
+        #[serde(rename = "AttachStdin")]
+        pub attach_stdin: bool,
+        #[serde(rename = "AttachStdout")]
+        pub attach_stdout: bool,
+        #[serde(rename = "AttachStderr")]
+        pub attach_stderr: bool,
+        #[serde(rename = "Tty")]
+        pub tty: bool,
+        #[serde(rename = "Cmd")]
+        pub cmd: Vec<String>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+    pub struct ExecStartConfig {
+        #[serde(rename = "Detach")]
+        pub detach: bool,
+        #[serde(rename = "Tty")]
+        pub tty: bool,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct IdResponse {
+        #[serde(rename = "Id")]
+        pub id: String,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RestfulApiExecRepository;
+    use crate::webapi::client::{ByteStream, Client};
+    use crate::webapi::request::{ApiRequest, ApiResponse, RequestBody};
+    use async_trait::async_trait;
+    use domain::exec::ExecRepository;
+    use hyper::upgrade::Upgraded;
+    use hyper::{HeaderMap, Method, StatusCode};
+    use std::error::Error;
+    use std::sync::Mutex;
+
+    /// Records the requests sent and answers each with the id of an exec instance.
+    #[derive(Default)]
+    struct RecordingClient {
+        requests: Mutex<Vec<ApiRequest>>,
+        upgrades: Mutex<Vec<(String, Vec<u8>)>>,
+    }
+
+    #[async_trait]
+    impl Client for RecordingClient {
+        async fn request(&self, request: ApiRequest) -> Result<ApiResponse, Box<dyn Error + Send + Sync>> {
+            self.requests.lock().unwrap().push(request);
+            Ok(ApiResponse {
+                status: StatusCode::CREATED,
+                headers: HeaderMap::new(),
+                body: br#"{"Id":"e1"}"#.to_vec(),
+            })
+        }
+
+        async fn stream(&self, _request: ApiRequest) -> Result<ByteStream, Box<dyn Error + Send + Sync>> {
+            Err("not streamed".into())
+        }
+
+        async fn upgrade(&self, path: &str, body: Vec<u8>) -> Result<Upgraded, Box<dyn Error + Send + Sync>> {
+            self.upgrades.lock().unwrap().push((path.to_string(), body));
+            Err("not upgraded".into())
+        }
+    }
+
+    #[tokio::test]
+    async fn creating_an_interactive_exec_instance() {
+        let client = RecordingClient::default();
+        let repository = RestfulApiExecRepository::new(&client);
+
+        let id = repository
+            .create("c1".to_string(), vec!["sh".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(id, "e1");
+        let request = client.requests.lock().unwrap().remove(0);
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.path_and_query(), "/containers/c1/exec");
+        assert_eq!(
+            request.body,
+            RequestBody::Json(
+                br#"{"AttachStdin":true,"AttachStdout":true,"AttachStderr":true,"Tty":true,"Cmd":["sh"]}"#.to_vec()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn starting_and_resizing_an_exec_instance() {
+        let client = RecordingClient::default();
+        let repository = RestfulApiExecRepository::new(&client);
+
+        assert!(repository.start("e1".to_string()).await.is_err());
+        assert_eq!(
+            client.upgrades.lock().unwrap().as_slice(),
+            &[("/exec/e1/start".to_string(), br#"{"Detach":false,"Tty":true}"#.to_vec())]
+        );
+
+        repository.resize("e1".to_string(), 80, 24).await.unwrap();
+        let request = client.requests.lock().unwrap().remove(0);
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.path_and_query(), "/exec/e1/resize?h=24&w=80");
+        assert_eq!(request.body, RequestBody::Empty);
+    }
+}
@@ -1,7 +1,13 @@
 use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use crate::webapi::stream::json_lines;
 use async_trait::async_trait;
-use domain::image::{Image, ImageHistory, ImageRecord, ImageRepository, ImageSummary};
-use std::collections::HashMap;
+use chrono::Utc;
+use domain::image::{
+    Image, ImageHistory, ImageRecord, ImageRemoveReport, ImageRepository, ImageSummary, PullProgress, PullStream,
+};
+use domain::size::ByteSize;
+use futures_util::stream::StreamExt;
 use std::error::Error;
 
 pub struct RestfulApiImageRepository<'a, T: Client> {
@@ -20,12 +26,23 @@ where
     T: Client + Send + Sync + 'static,
 {
     async fn list(&self) -> Result<Vec<ImageSummary>, Box<dyn Error + Send + Sync>> {
-        let bytes = self.client.get("/images/json").await?;
+        // Daemons before API 1.42 ignore `shared-size` and report -1.
+        let request = ApiRequest::get("/images/json").query("shared-size", true);
+        let bytes = self.client.request(request).await?.body;
 
         let images: Vec<types::ImageSummary> = serde_json::from_slice(&bytes)?;
         let items = images
             .into_iter()
-            .map(|v| ImageSummary::from_repository(v.id, v.repo_tags, v.created.unwrap(), v.size))
+            .map(|v| ImageSummary {
+                id: v.id,
+                repo_tags: references(v.repo_tags),
+                repo_digests: references(v.repo_digests),
+                parent_id: Some(v.parent_id).filter(|p| !p.is_empty()),
+                created: v.created.unwrap_or_else(Utc::now),
+                size: ByteSize::from_signed(v.size).unwrap_or_default(),
+                shared_size: ByteSize::from_signed(v.shared_size),
+                containers: known(v.containers),
+            })
             .collect();
         Ok(items)
     }
@@ -41,8 +58,8 @@ where
             architecture: detail.architecture,
             env: detail.config.env,
             entrypoint: detail.config.entrypoint,
-            cmd: detail.config.cmd.unwrap_or_else(Vec::new),
-            labels: detail.container_config.labels.unwrap_or_else(HashMap::new),
+            cmd: detail.config.cmd.unwrap_or_default(),
+            labels: detail.container_config.labels.unwrap_or_default(),
         })
     }
 
@@ -54,12 +71,79 @@ where
             let item = ImageRecord {
                 id: record.id,
                 created_by: record.created_by,
-                size: record.size,
+                size: ByteSize::from_signed(record.size).unwrap_or_default(),
             };
             items.push(item);
         }
         Ok(items)
     }
+
+    async fn pull(&self, reference: String) -> Result<PullStream, Box<dyn Error + Send + Sync>> {
+        // The tag or digest stays in `fromImage`; the daemon would pull every tag without one.
+        let request = ApiRequest::post("/images/create").query("fromImage", reference);
+        let chunks = self.client.stream(request).await?;
+        let progress = json_lines::<types::PullMessage>(chunks).map(|message| {
+            let message = message?;
+            // A failed pull still answers 200, the error comes as the last message.
+            if let Some(error) = message.error_detail.map(|e| e.message).or(message.error) {
+                return Err(error.into());
+            }
+            let detail = message.progress_detail.unwrap_or_default();
+            Ok(PullProgress {
+                id: message.id,
+                status: message.status.unwrap_or_default(),
+                current: detail.current,
+                total: detail.total,
+            })
+        });
+        Ok(Box::pin(progress))
+    }
+
+    async fn remove(
+        &self,
+        id: String,
+        force: bool,
+        no_prune: bool,
+    ) -> Result<ImageRemoveReport, Box<dyn Error + Send + Sync>> {
+        let request = ApiRequest::delete(format!("/images/{}", id))
+            .query("force", force)
+            .query("noprune", no_prune);
+        let bytes = self.client.request(request).await?.body;
+        let items: Vec<types::ImageDeleteResponseItem> = serde_json::from_slice(&bytes)?;
+        let mut report = ImageRemoveReport::default();
+        for item in items.into_iter() {
+            report.untagged.extend(item.untagged);
+            report.deleted.extend(item.deleted);
+        }
+        Ok(report)
+    }
+
+    async fn tag(&self, id: String, repo: String, tag: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let request = ApiRequest::post(format!("/images/{}/tag", id))
+            .query("repo", repo)
+            .query("tag", tag);
+        self.client.request(request).await?;
+        Ok(())
+    }
+}
+
+/// Daemons before API 1.43 report `<none>:<none>` and `<none>@<none>` for untagged images, newer ones
+/// `null` or an empty list.
+fn references(references: Option<Vec<String>>) -> Vec<String> {
+    references
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|r| !r.starts_with("<none>"))
+        .collect()
+}
+
+/// The daemon reports -1 for counts it didn't compute.
+fn known(value: i64) -> Option<u64> {
+    if value < 0 {
+        None
+    } else {
+        Some(value as u64)
+    }
 }
 
 mod types {
@@ -73,24 +157,25 @@ mod types {
     pub struct ImageSummary {
         #[serde(rename = "Id")]
         pub id: String,
-        #[serde(rename = "ParentId")]
+        #[serde(rename = "ParentId", default)]
         pub parent_id: String,
         #[serde(rename = "RepoTags")]
-        pub repo_tags: Vec<String>,
+        pub repo_tags: Option<Vec<String>>,
         #[serde(rename = "RepoDigests")]
         pub repo_digests: Option<Vec<String>>,
         #[serde(rename = "Created", with = "date_format")]
         pub created: Option<DateTime<Utc>>,
         #[serde(rename = "Size")]
-        pub size: i32,
-        #[serde(rename = "VirtualSize")]
-        pub virtual_size: i32,
+        pub size: i64,
+        // Removed in API 1.44.
+        #[serde(rename = "VirtualSize", default)]
+        pub virtual_size: i64,
         #[serde(rename = "SharedSize")]
-        pub shared_size: i32,
+        pub shared_size: i64,
         #[serde(rename = "Labels")]
         pub labels: Option<HashMap<String, String>>,
         #[serde(rename = "Containers")]
-        pub containers: i32,
+        pub containers: i64,
     }
     #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
     #[serde(rename_all = "camelCase")]
@@ -112,9 +197,9 @@ mod types {
         #[serde(rename = "DockerVersion")]
         pub docker_version: String,
         #[serde(rename = "VirtualSize")]
-        pub virtual_size: i32,
+        pub virtual_size: i64,
         #[serde(rename = "Size")]
-        pub size: i32,
+        pub size: i64,
         #[serde(rename = "Author")]
         pub author: String,
         #[serde(rename = "Created")]
@@ -246,8 +331,35 @@ mod types {
         #[serde(rename = "Tags")]
         pub tags: Option<Vec<String>>,
         #[serde(rename = "Size")]
-        pub size: i32,
+        pub size: i64,
         #[serde(rename = "Comment")]
         pub comment: String,
     }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct PullMessage {
+        pub id: Option<String>,
+        pub status: Option<String>,
+        #[serde(rename = "progressDetail")]
+        pub progress_detail: Option<ProgressDetail>,
+        pub error: Option<String>,
+        #[serde(rename = "errorDetail")]
+        pub error_detail: Option<ErrorDetail>,
+    }
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct ProgressDetail {
+        pub current: Option<u64>,
+        pub total: Option<u64>,
+    }
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct ErrorDetail {
+        pub message: String,
+    }
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct ImageDeleteResponseItem {
+        #[serde(rename = "Untagged")]
+        pub untagged: Option<String>,
+        #[serde(rename = "Deleted")]
+        pub deleted: Option<String>,
+    }
 }
@@ -0,0 +1,148 @@
+use crate::shared::stdcopy::{Demuxer, LineBuffer, StdStream};
+use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use crate::webapi::stream::{decode, Decoder};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::log::{LogLine, LogOptions, LogRepository, LogSource, LogStream};
+use std::error::Error;
+
+pub struct RestfulApiLogRepository<'a, T: Client> {
+    client: &'a T,
+}
+
+impl<'a, T: Client> RestfulApiLogRepository<'a, T> {
+    pub fn new(client: &'a T) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<'a, T> LogRepository for RestfulApiLogRepository<'a, T>
+where
+    T: Client + Send + Sync + 'static,
+{
+    async fn logs(&self, id: String, options: LogOptions) -> Result<LogStream, Box<dyn Error + Send + Sync>> {
+        // The daemon only multiplexes stdout and stderr when the container has no TTY.
+        let bytes = self.client.get(&format!("/containers/{}/json", id)).await?;
+        let container: types::ContainerInspect = serde_json::from_slice(&bytes)?;
+
+        let request = ApiRequest::get(format!("/containers/{}/logs", id))
+            .query("stdout", 1)
+            .query("stderr", 1)
+            .query("follow", options.follow)
+            .query("timestamps", options.timestamps)
+            .query("tail", options.tail.map_or("all".to_string(), |v| v.to_string()))
+            .query_opt("since", options.since.map(|v| v.timestamp()));
+        let chunks = self.client.stream(request).await?;
+        let decoder = LogDecoder::new(container.config.tty, options.timestamps);
+        Ok(Box::pin(decode(chunks, decoder)))
+    }
+}
+
+struct LogDecoder {
+    tty: bool,
+    timestamps: bool,
+    demuxer: Demuxer,
+    stdout: LineBuffer,
+    stderr: LineBuffer,
+}
+
+impl LogDecoder {
+    fn new(tty: bool, timestamps: bool) -> Self {
+        Self {
+            tty,
+            timestamps,
+            demuxer: Demuxer::new(),
+            stdout: LineBuffer::new(),
+            stderr: LineBuffer::new(),
+        }
+    }
+
+    fn lines(&mut self, chunk: &[u8]) -> Vec<LogLine> {
+        if self.tty {
+            let lines = self.stdout.push(chunk);
+            return self.to_log_lines(LogSource::Stdout, lines);
+        }
+
+        let mut lines = Vec::new();
+        for (stream, payload) in self.demuxer.push(chunk).into_iter() {
+            let (source, buffer) = match stream {
+                StdStream::Stderr => (LogSource::Stderr, &mut self.stderr),
+                _ => (LogSource::Stdout, &mut self.stdout),
+            };
+            let decoded = buffer.push(&payload);
+            lines.extend(self.to_log_lines(source, decoded));
+        }
+        lines
+    }
+
+    fn remaining_lines(&mut self) -> Vec<LogLine> {
+        let stdout = self.stdout.flush().into_iter().collect();
+        let stderr = self.stderr.flush().into_iter().collect();
+        let mut lines = self.to_log_lines(LogSource::Stdout, stdout);
+        lines.extend(self.to_log_lines(LogSource::Stderr, stderr));
+        lines
+    }
+
+    fn to_log_lines(&self, source: LogSource, lines: Vec<String>) -> Vec<LogLine> {
+        lines
+            .into_iter()
+            .map(|line| {
+                let (timestamp, message) = if self.timestamps {
+                    split_timestamp(line)
+                } else {
+                    (None, line)
+                };
+                LogLine {
+                    source,
+                    timestamp,
+                    message,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Decoder for LogDecoder {
+    type Item = LogLine;
+
+    fn push(&mut self, chunk: &[u8]) -> Vec<Result<LogLine, Box<dyn Error + Send + Sync>>> {
+        self.lines(chunk).into_iter().map(Ok).collect()
+    }
+
+    fn flush(&mut self) -> Vec<Result<LogLine, Box<dyn Error + Send + Sync>>> {
+        self.remaining_lines().into_iter().map(Ok).collect()
+    }
+}
+
+/// Splits the RFC 3339 timestamp the daemon prepends to each line when `timestamps=1`.
+fn split_timestamp(line: String) -> (Option<DateTime<Utc>>, String) {
+    let mut parts = line.splitn(2, ' ');
+    let timestamp = parts
+        .next()
+        .and_then(|v| DateTime::parse_from_rfc3339(v).ok())
+        .map(|v| v.with_timezone(&Utc));
+    match timestamp {
+        Some(timestamp) => (Some(timestamp), parts.next().unwrap_or_default().to_string()),
+        None => (None, line),
+    }
+}
+
+mod types {
+    use serde::Deserialize;
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ContainerInspect {
+        #[serde(rename = "Config")]
+        pub config: Config,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Config {
+        #[serde(rename = "Tty")]
+        pub tty: bool,
+    }
+}
@@ -1,3 +1,11 @@
 pub mod client;
 pub mod container_repository;
+pub mod event_repository;
+pub mod exec_repository;
 pub mod image_repository;
+pub mod log_repository;
+pub mod network_repository;
+pub mod stats_repository;
+pub mod system_repository;
+pub mod tls;
+pub mod volume_repository;
@@ -0,0 +1,227 @@
+use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use async_trait::async_trait;
+use domain::network::{
+    CreateNetworkOptions, Ipam, IpamConfig, Network, NetworkEndpoint, NetworkPruneReport, NetworkRepository,
+};
+use std::error::Error;
+
+pub struct RestfulApiNetworkRepository<'a, T: Client> {
+    client: &'a T,
+}
+
+impl<'a, T: Client> RestfulApiNetworkRepository<'a, T> {
+    pub fn new(client: &'a T) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<'a, T> NetworkRepository for RestfulApiNetworkRepository<'a, T>
+where
+    T: Client + Send + Sync + 'static,
+{
+    async fn list(&self) -> Result<Vec<Network>, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.get("/networks").await?;
+        let networks: Vec<types::Network> = serde_json::from_slice(&bytes)?;
+        Ok(networks.into_iter().map(to_network).collect())
+    }
+
+    async fn inspect(&self, id: String) -> Result<Network, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.get(&format!("/networks/{}", id)).await?;
+        let network: types::Network = serde_json::from_slice(&bytes)?;
+        Ok(to_network(network))
+    }
+
+    async fn create(&self, options: CreateNetworkOptions) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let body = types::NetworkCreateRequest {
+            name: options.name,
+            check_duplicate: true,
+            driver: if options.driver.is_empty() {
+                "bridge".to_string()
+            } else {
+                options.driver
+            },
+            internal: options.internal,
+            attachable: options.attachable,
+            labels: options.labels,
+        };
+        let request = ApiRequest::post("/networks/create").json(&body)?;
+        let bytes = self.client.request(request).await?.body;
+        let response: types::NetworkCreateResponse = serde_json::from_slice(&bytes)?;
+        Ok(response.id)
+    }
+
+    async fn remove(&self, id: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.client.delete(&format!("/networks/{}", id)).await?;
+        Ok(())
+    }
+
+    async fn prune(&self) -> Result<NetworkPruneReport, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.post("/networks/prune").await?;
+        let response: types::NetworkPruneResponse = serde_json::from_slice(&bytes)?;
+        Ok(NetworkPruneReport {
+            networks_deleted: response.networks_deleted.unwrap_or_default(),
+        })
+    }
+
+    async fn connect(&self, id: String, container: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = types::NetworkConnectRequest { container };
+        let request = ApiRequest::post(format!("/networks/{}/connect", id)).json(&body)?;
+        self.client.request(request).await?;
+        Ok(())
+    }
+
+    async fn disconnect(&self, id: String, container: String, force: bool) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = types::NetworkDisconnectRequest { container, force };
+        let request = ApiRequest::post(format!("/networks/{}/disconnect", id)).json(&body)?;
+        self.client.request(request).await?;
+        Ok(())
+    }
+}
+
+fn to_network(network: types::Network) -> Network {
+    let ipam = network.ipam.unwrap_or_default();
+    Network {
+        id: network.id,
+        name: network.name,
+        driver: network.driver,
+        scope: network.scope,
+        created: network.created,
+        internal: network.internal,
+        attachable: network.attachable,
+        ipam: Ipam {
+            driver: ipam.driver,
+            config: ipam
+                .config
+                .unwrap_or_default()
+                .into_iter()
+                .map(|c| IpamConfig {
+                    subnet: c.subnet.unwrap_or_default(),
+                    gateway: c.gateway.unwrap_or_default(),
+                    ip_range: c.ip_range.unwrap_or_default(),
+                })
+                .collect(),
+        },
+        containers: network
+            .containers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, c)| {
+                let endpoint = NetworkEndpoint {
+                    name: c.name,
+                    ipv4_address: c.ipv4_address,
+                    ipv6_address: c.ipv6_address,
+                    mac_address: c.mac_address,
+                };
+                (id, endpoint)
+            })
+            .collect(),
+        options: network.options.unwrap_or_default(),
+        labels: network.labels.unwrap_or_default(),
+    }
+}
+
+mod types {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Network {
+        #[serde(rename = "Id")]
+        pub id: String,
+        #[serde(rename = "Name")]
+        pub name: String,
+        #[serde(rename = "Driver", default)]
+        pub driver: String,
+        #[serde(rename = "Scope", default)]
+        pub scope: String,
+        #[serde(rename = "Created")]
+        pub created: Option<DateTime<Utc>>,
+        #[serde(rename = "Internal", default)]
+        pub internal: bool,
+        #[serde(rename = "Attachable", default)]
+        pub attachable: bool,
+        #[serde(rename = "IPAM")]
+        pub ipam: Option<Ipam>,
+        #[serde(rename = "Containers")]
+        pub containers: Option<HashMap<String, Endpoint>>,
+        #[serde(rename = "Options")]
+        pub options: Option<HashMap<String, String>>,
+        #[serde(rename = "Labels")]
+        pub labels: Option<HashMap<String, String>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Ipam {
+        #[serde(rename = "Driver", default)]
+        pub driver: String,
+        #[serde(rename = "Config")]
+        pub config: Option<Vec<IpamConfig>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct IpamConfig {
+        #[serde(rename = "Subnet")]
+        pub subnet: Option<String>,
+        #[serde(rename = "Gateway")]
+        pub gateway: Option<String>,
+        #[serde(rename = "IPRange")]
+        pub ip_range: Option<String>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Endpoint {
+        #[serde(rename = "Name", default)]
+        pub name: String,
+        #[serde(rename = "IPv4Address", default)]
+        pub ipv4_address: String,
+        #[serde(rename = "IPv6Address", default)]
+        pub ipv6_address: String,
+        #[serde(rename = "MacAddress", default)]
+        pub mac_address: String,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+    pub struct NetworkCreateRequest {
+        #[serde(rename = "Name")]
+        pub name: String,
+        #[serde(rename = "CheckDuplicate")]
+        pub check_duplicate: bool,
+        #[serde(rename = "Driver")]
+        pub driver: String,
+        #[serde(rename = "Internal")]
+        pub internal: bool,
+        #[serde(rename = "Attachable")]
+        pub attachable: bool,
+        #[serde(rename = "Labels")]
+        pub labels: HashMap<String, String>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct NetworkCreateResponse {
+        #[serde(rename = "Id")]
+        pub id: String,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct NetworkPruneResponse {
+        #[serde(rename = "NetworksDeleted")]
+        pub networks_deleted: Option<Vec<String>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+    pub struct NetworkConnectRequest {
+        #[serde(rename = "Container")]
+        pub container: String,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+    pub struct NetworkDisconnectRequest {
+        #[serde(rename = "Container")]
+        pub container: String,
+        #[serde(rename = "Force")]
+        pub force: bool,
+    }
+}
@@ -0,0 +1,155 @@
+use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use crate::webapi::stream::json_lines;
+use async_trait::async_trait;
+use domain::stats::{CpuUsage, StatsRepository, StatsSample, StatsStream};
+use futures_util::stream::StreamExt;
+use std::error::Error;
+
+pub struct RestfulApiStatsRepository<'a, T: Client> {
+    client: &'a T,
+}
+
+impl<'a, T: Client> RestfulApiStatsRepository<'a, T> {
+    pub fn new(client: &'a T) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<'a, T> StatsRepository for RestfulApiStatsRepository<'a, T>
+where
+    T: Client + Send + Sync + 'static,
+{
+    async fn stats(&self, id: String) -> Result<StatsStream, Box<dyn Error + Send + Sync>> {
+        let request = ApiRequest::get(format!("/containers/{}/stats", id)).query("stream", true);
+        let chunks = self.client.stream(request).await?;
+        let samples = json_lines::<types::Stats>(chunks).map(|stats| stats.map(to_sample));
+        Ok(Box::pin(samples))
+    }
+}
+
+fn to_sample(stats: types::Stats) -> StatsSample {
+    let memory = stats.memory_stats;
+    let memory_cache = memory
+        .stats
+        .map(|s| {
+            // cgroup v1 reports `total_inactive_file`, v2 `inactive_file` and old daemons only `cache`.
+            s.total_inactive_file
+                .or(s.inactive_file)
+                .or(s.cache)
+                .unwrap_or_default()
+        })
+        .unwrap_or_default();
+    let (network_rx_bytes, network_tx_bytes) = stats
+        .networks
+        .unwrap_or_default()
+        .values()
+        .fold((0, 0), |(rx, tx), n| (rx + n.rx_bytes, tx + n.tx_bytes));
+    let (block_read_bytes, block_write_bytes) = stats
+        .blkio_stats
+        .io_service_bytes_recursive
+        .unwrap_or_default()
+        .iter()
+        .fold((0, 0), |(read, write), e| match e.op.to_lowercase().as_str() {
+            "read" => (read + e.value, write),
+            "write" => (read, write + e.value),
+            _ => (read, write),
+        });
+
+    StatsSample {
+        read: stats.read,
+        cpu: to_cpu_usage(stats.cpu_stats),
+        precpu: to_cpu_usage(stats.precpu_stats),
+        memory_usage: memory.usage.unwrap_or_default(),
+        memory_cache,
+        memory_limit: memory.limit.unwrap_or_default(),
+        network_rx_bytes,
+        network_tx_bytes,
+        block_read_bytes,
+        block_write_bytes,
+        pids: stats.pids_stats.current.unwrap_or_default(),
+    }
+}
+
+fn to_cpu_usage(stats: types::CpuStats) -> CpuUsage {
+    let percpu = stats.cpu_usage.percpu_usage.map_or(0, |v| v.len() as u32);
+    CpuUsage {
+        total_usage: stats.cpu_usage.total_usage,
+        system_usage: stats.system_cpu_usage.unwrap_or_default(),
+        online_cpus: stats.online_cpus.unwrap_or(percpu),
+    }
+}
+
+mod types {
+    use chrono::{DateTime, Utc};
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    pub struct Stats {
+        pub read: DateTime<Utc>,
+        #[serde(default)]
+        pub cpu_stats: CpuStats,
+        #[serde(default)]
+        pub precpu_stats: CpuStats,
+        #[serde(default)]
+        pub memory_stats: MemoryStats,
+        pub networks: Option<HashMap<String, NetworkStats>>,
+        #[serde(default)]
+        pub blkio_stats: BlkioStats,
+        #[serde(default)]
+        pub pids_stats: PidsStats,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct CpuStats {
+        #[serde(default)]
+        pub cpu_usage: CpuUsage,
+        pub system_cpu_usage: Option<u64>,
+        pub online_cpus: Option<u32>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct CpuUsage {
+        #[serde(default)]
+        pub total_usage: u64,
+        pub percpu_usage: Option<Vec<u64>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct MemoryStats {
+        pub usage: Option<u64>,
+        pub limit: Option<u64>,
+        pub stats: Option<MemoryDetail>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct MemoryDetail {
+        pub cache: Option<u64>,
+        pub inactive_file: Option<u64>,
+        pub total_inactive_file: Option<u64>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct NetworkStats {
+        pub rx_bytes: u64,
+        pub tx_bytes: u64,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct BlkioStats {
+        pub io_service_bytes_recursive: Option<Vec<BlkioEntry>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct BlkioEntry {
+        pub op: String,
+        pub value: u64,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct PidsStats {
+        pub current: Option<u64>,
+    }
+}
@@ -0,0 +1,217 @@
+use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use async_trait::async_trait;
+use domain::size::ByteSize;
+use domain::system::{
+    BuildCacheUsage, ContainerUsage, DiskUsage, ImageUsage, PruneOptions, PruneReport, PruneTarget, SystemRepository,
+};
+use domain::volume::VolumeUsage;
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct RestfulApiSystemRepository<'a, T: Client> {
+    client: &'a T,
+}
+
+impl<'a, T: Client> RestfulApiSystemRepository<'a, T> {
+    pub fn new(client: &'a T) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl<'a, T> SystemRepository for RestfulApiSystemRepository<'a, T>
+where
+    T: Client + Send + Sync + 'static,
+{
+    async fn disk_usage(&self) -> Result<DiskUsage, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.get("/system/df").await?;
+        let df: types::DiskUsage = serde_json::from_slice(&bytes)?;
+        let images = df
+            .images
+            .unwrap_or_default()
+            .into_iter()
+            .map(|i| ImageUsage {
+                size: ByteSize::from_signed(i.size).unwrap_or_default(),
+                shared_size: ByteSize::from_signed(i.shared_size),
+                containers: known(i.containers),
+            })
+            .collect();
+        let containers = df
+            .containers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| ContainerUsage {
+                size_rw: c.size_rw.and_then(ByteSize::from_signed),
+                running: c.state == "running",
+            })
+            .collect();
+        let volumes = df
+            .volumes
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|v| v.usage_data)
+            .map(|u| VolumeUsage {
+                size: ByteSize::from_signed(u.size),
+                ref_count: known(u.ref_count).unwrap_or_default(),
+            })
+            .collect();
+        let build_cache = df
+            .build_cache
+            .unwrap_or_default()
+            .into_iter()
+            .map(|b| BuildCacheUsage {
+                size: ByteSize::from_signed(b.size).unwrap_or_default(),
+                in_use: b.in_use,
+                shared: b.shared,
+            })
+            .collect();
+        Ok(DiskUsage {
+            layers_size: ByteSize::from_signed(df.layers_size).unwrap_or_default(),
+            images,
+            containers,
+            volumes,
+            build_cache,
+        })
+    }
+
+    async fn prune(
+        &self,
+        target: PruneTarget,
+        options: PruneOptions,
+    ) -> Result<PruneReport, Box<dyn Error + Send + Sync>> {
+        let mut filters = HashMap::new();
+        if let Some(until) = options.until.as_deref() {
+            filters.insert("until", vec![until]);
+        }
+        let request = match target {
+            PruneTarget::Images => {
+                if options.all {
+                    filters.insert("dangling", vec!["false"]);
+                }
+                ApiRequest::post("/images/prune").filters(&filters)?
+            }
+            PruneTarget::Containers => ApiRequest::post("/containers/prune").filters(&filters)?,
+            PruneTarget::Volumes => {
+                if options.until.is_some() {
+                    return Err("volumes can't be pruned by age".into());
+                }
+                // Daemons before API 1.42 ignore it and prune named volumes anyway.
+                if options.all {
+                    filters.insert("all", vec!["true"]);
+                }
+                ApiRequest::post("/volumes/prune").filters(&filters)?
+            }
+            PruneTarget::BuildCache => ApiRequest::post("/build/prune")
+                .query("all", options.all)
+                .filters(&filters)?,
+        };
+        let bytes = self.client.request(request).await?.body;
+        let response: types::PruneResponse = serde_json::from_slice(&bytes)?;
+        let deleted = match target {
+            PruneTarget::Images => response
+                .images_deleted
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|i| i.deleted)
+                .collect(),
+            PruneTarget::Containers => response.containers_deleted.unwrap_or_default(),
+            PruneTarget::Volumes => response.volumes_deleted.unwrap_or_default(),
+            PruneTarget::BuildCache => response.caches_deleted.unwrap_or_default(),
+        };
+        Ok(PruneReport {
+            deleted,
+            space_reclaimed: ByteSize(response.space_reclaimed),
+        })
+    }
+}
+
+/// The daemon reports -1 for counts it doesn't know.
+fn known(value: i64) -> Option<u64> {
+    if value < 0 {
+        None
+    } else {
+        Some(value as u64)
+    }
+}
+
+mod types {
+    use serde::Deserialize;
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct DiskUsage {
+        #[serde(rename = "LayersSize", default)]
+        pub layers_size: i64,
+        #[serde(rename = "Images")]
+        pub images: Option<Vec<ImageSummary>>,
+        #[serde(rename = "Containers")]
+        pub containers: Option<Vec<ContainerSummary>>,
+        #[serde(rename = "Volumes")]
+        pub volumes: Option<Vec<Volume>>,
+        #[serde(rename = "BuildCache")]
+        pub build_cache: Option<Vec<BuildCache>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct ImageSummary {
+        #[serde(rename = "Size")]
+        pub size: i64,
+        #[serde(rename = "SharedSize")]
+        pub shared_size: i64,
+        #[serde(rename = "Containers")]
+        pub containers: i64,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct ContainerSummary {
+        #[serde(rename = "SizeRw")]
+        pub size_rw: Option<i64>,
+        #[serde(rename = "State")]
+        pub state: String,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Volume {
+        #[serde(rename = "UsageData")]
+        pub usage_data: Option<UsageData>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct UsageData {
+        #[serde(rename = "Size")]
+        pub size: i64,
+        #[serde(rename = "RefCount")]
+        pub ref_count: i64,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct BuildCache {
+        #[serde(rename = "Size")]
+        pub size: i64,
+        #[serde(rename = "InUse", default)]
+        pub in_use: bool,
+        #[serde(rename = "Shared", default)]
+        pub shared: bool,
+    }
+
+    /// The prune endpoints only differ in the name of their list of deleted objects.
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct PruneResponse {
+        #[serde(rename = "ImagesDeleted")]
+        pub images_deleted: Option<Vec<ImageDeleteResponseItem>>,
+        #[serde(rename = "ContainersDeleted")]
+        pub containers_deleted: Option<Vec<String>>,
+        #[serde(rename = "VolumesDeleted")]
+        pub volumes_deleted: Option<Vec<String>>,
+        #[serde(rename = "CachesDeleted")]
+        pub caches_deleted: Option<Vec<String>>,
+        #[serde(rename = "SpaceReclaimed", default)]
+        pub space_reclaimed: u64,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct ImageDeleteResponseItem {
+        #[serde(rename = "Deleted")]
+        pub deleted: Option<String>,
+    }
+}
@@ -0,0 +1,185 @@
+use crate::webapi::client::Client;
+use crate::webapi::request::ApiRequest;
+use async_trait::async_trait;
+use domain::size::ByteSize;
+use domain::volume::{CreateVolumeOptions, Volume, VolumePruneReport, VolumeRepository, VolumeUsage};
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct RestfulApiVolumeRepository<'a, T: Client> {
+    client: &'a T,
+}
+
+impl<'a, T: Client> RestfulApiVolumeRepository<'a, T> {
+    pub fn new(client: &'a T) -> Self {
+        Self { client }
+    }
+
+    /// Sizes and reference counts by volume name. `/volumes` leaves them out, only the disk usage
+    /// endpoint computes them.
+    async fn usages(&self) -> Result<HashMap<String, VolumeUsage>, Box<dyn Error + Send + Sync>> {
+        // Daemons before API 1.42 ignore `type` and report every kind of object.
+        let request = ApiRequest::get("/system/df").query("type", "volume");
+        let bytes = self.client.request(request).await?.body;
+        let df: types::DiskUsage = serde_json::from_slice(&bytes)?;
+        let usages = df
+            .volumes
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|v| {
+                let name = v.name;
+                v.usage_data.map(|usage| (name, to_usage(usage)))
+            })
+            .collect();
+        Ok(usages)
+    }
+}
+
+#[async_trait]
+impl<'a, T> VolumeRepository for RestfulApiVolumeRepository<'a, T>
+where
+    T: Client + Send + Sync + 'static,
+{
+    async fn list(&self) -> Result<Vec<Volume>, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.get("/volumes").await?;
+        let response: types::VolumeListResponse = serde_json::from_slice(&bytes)?;
+        // The list is still worth showing without usage, e.g. when the daemon is too old to report it.
+        let mut usages = self.usages().await.unwrap_or_default();
+        let items = response
+            .volumes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|v| {
+                let usage = usages.remove(&v.name);
+                to_volume(v, usage)
+            })
+            .collect();
+        Ok(items)
+    }
+
+    async fn inspect(&self, name: String) -> Result<Volume, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.get(&format!("/volumes/{}", name)).await?;
+        let volume: types::Volume = serde_json::from_slice(&bytes)?;
+        let usage = volume.usage_data.clone().map(to_usage);
+        Ok(to_volume(volume, usage))
+    }
+
+    async fn create(&self, options: CreateVolumeOptions) -> Result<Volume, Box<dyn Error + Send + Sync>> {
+        let body = types::VolumeCreateRequest {
+            name: options.name,
+            driver: if options.driver.is_empty() {
+                "local".to_string()
+            } else {
+                options.driver
+            },
+            driver_opts: options.driver_opts,
+            labels: options.labels,
+        };
+        let request = ApiRequest::post("/volumes/create").json(&body)?;
+        let bytes = self.client.request(request).await?.body;
+        let volume: types::Volume = serde_json::from_slice(&bytes)?;
+        Ok(to_volume(volume, None))
+    }
+
+    async fn remove(&self, name: String, force: bool) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let request = ApiRequest::delete(format!("/volumes/{}", name)).query("force", force);
+        self.client.request(request).await?;
+        Ok(())
+    }
+
+    async fn prune(&self) -> Result<VolumePruneReport, Box<dyn Error + Send + Sync>> {
+        let bytes = self.client.post("/volumes/prune").await?;
+        let response: types::VolumePruneResponse = serde_json::from_slice(&bytes)?;
+        Ok(VolumePruneReport {
+            volumes_deleted: response.volumes_deleted.unwrap_or_default(),
+            space_reclaimed: ByteSize(response.space_reclaimed),
+        })
+    }
+}
+
+fn to_volume(volume: types::Volume, usage: Option<VolumeUsage>) -> Volume {
+    Volume {
+        name: volume.name,
+        driver: volume.driver,
+        mountpoint: volume.mountpoint,
+        created: volume.created_at,
+        scope: volume.scope,
+        labels: volume.labels.unwrap_or_default(),
+        options: volume.options.unwrap_or_default(),
+        usage,
+    }
+}
+
+/// The daemon reports -1 for values it doesn't know.
+fn to_usage(usage: types::UsageData) -> VolumeUsage {
+    VolumeUsage {
+        size: ByteSize::from_signed(usage.size),
+        ref_count: usage.ref_count.max(0) as u64,
+    }
+}
+
+mod types {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct VolumeListResponse {
+        #[serde(rename = "Volumes")]
+        pub volumes: Option<Vec<Volume>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct Volume {
+        #[serde(rename = "Name")]
+        pub name: String,
+        #[serde(rename = "Driver")]
+        pub driver: String,
+        #[serde(rename = "Mountpoint")]
+        pub mountpoint: String,
+        #[serde(rename = "CreatedAt")]
+        pub created_at: Option<DateTime<Utc>>,
+        #[serde(rename = "Scope", default)]
+        pub scope: String,
+        #[serde(rename = "Labels")]
+        pub labels: Option<HashMap<String, String>>,
+        #[serde(rename = "Options")]
+        pub options: Option<HashMap<String, String>>,
+        #[serde(rename = "UsageData")]
+        pub usage_data: Option<UsageData>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct UsageData {
+        #[serde(rename = "Size")]
+        pub size: i64,
+        #[serde(rename = "RefCount")]
+        pub ref_count: i64,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct DiskUsage {
+        #[serde(rename = "Volumes")]
+        pub volumes: Option<Vec<Volume>>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Serialize)]
+    pub struct VolumeCreateRequest {
+        #[serde(rename = "Name")]
+        pub name: String,
+        #[serde(rename = "Driver")]
+        pub driver: String,
+        #[serde(rename = "DriverOpts")]
+        pub driver_opts: HashMap<String, String>,
+        #[serde(rename = "Labels")]
+        pub labels: HashMap<String, String>,
+    }
+
+    #[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+    pub struct VolumePruneResponse {
+        #[serde(rename = "VolumesDeleted")]
+        pub volumes_deleted: Option<Vec<String>>,
+        #[serde(rename = "SpaceReclaimed", default)]
+        pub space_reclaimed: u64,
+    }
+}
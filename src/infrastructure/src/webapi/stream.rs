@@ -0,0 +1,156 @@
+use crate::shared::stdcopy::LineBuffer;
+use crate::webapi::client::ByteStream;
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::marker::PhantomData;
+
+/// Turns the chunks of a streamed response body into items, keeping whatever is incomplete for the next chunk.
+pub trait Decoder: Send + 'static {
+    type Item: Send + 'static;
+
+    fn push(&mut self, chunk: &[u8]) -> Vec<Result<Self::Item, Box<dyn Error + Send + Sync>>>;
+    /// Called once the body ended, for a trailing item without its terminator.
+    fn flush(&mut self) -> Vec<Result<Self::Item, Box<dyn Error + Send + Sync>>>;
+}
+
+struct DecodeState<D: Decoder> {
+    chunks: ByteStream,
+    decoder: D,
+    pending: VecDeque<Result<D::Item, Box<dyn Error + Send + Sync>>>,
+    done: bool,
+}
+
+/// Decodes the body as it arrives. The stream ends after the first error; dropping it drops the
+/// response body, which closes the connection and stops the daemon from following.
+pub fn decode<D: Decoder>(
+    chunks: ByteStream,
+    decoder: D,
+) -> impl Stream<Item = Result<D::Item, Box<dyn Error + Send + Sync>>> + Send {
+    let state = DecodeState {
+        chunks,
+        decoder,
+        pending: VecDeque::new(),
+        done: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.pending.pop_front() {
+                if item.is_err() {
+                    state.pending.clear();
+                    state.done = true;
+                }
+                return Some((item, state));
+            }
+            if state.done {
+                return None;
+            }
+            match state.chunks.next().await {
+                Some(Ok(chunk)) => {
+                    let items = state.decoder.push(&chunk);
+                    state.pending.extend(items);
+                }
+                Some(Err(e)) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+                None => {
+                    state.done = true;
+                    let items = state.decoder.flush();
+                    state.pending.extend(items);
+                }
+            }
+        }
+    })
+}
+
+/// Decodes newline-delimited JSON, which the daemon uses for stats, events and pull progress.
+pub fn json_lines<T>(chunks: ByteStream) -> impl Stream<Item = Result<T, Box<dyn Error + Send + Sync>>> + Send
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    decode(chunks, JsonLines::<T>::new())
+}
+
+pub struct JsonLines<T> {
+    lines: LineBuffer,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonLines<T> {
+    pub fn new() -> Self {
+        Self {
+            lines: LineBuffer::new(),
+            item: PhantomData,
+        }
+    }
+
+    fn parse(lines: impl IntoIterator<Item = String>) -> Vec<Result<T, Box<dyn Error + Send + Sync>>>
+    where
+        T: DeserializeOwned,
+    {
+        lines
+            .into_iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(&line).map_err(|e| e.into()))
+            .collect()
+    }
+}
+
+impl<T> Default for JsonLines<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Decoder for JsonLines<T>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    type Item = T;
+
+    fn push(&mut self, chunk: &[u8]) -> Vec<Result<T, Box<dyn Error + Send + Sync>>> {
+        Self::parse(self.lines.push(chunk))
+    }
+
+    fn flush(&mut self) -> Vec<Result<T, Box<dyn Error + Send + Sync>>> {
+        Self::parse(self.lines.flush())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::json_lines;
+    use futures_util::stream::{self, StreamExt};
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Progress {
+        status: String,
+    }
+
+    #[tokio::test]
+    async fn decoding_json_split_across_chunks() {
+        let chunks = vec![
+            Ok(br#"{"status":"Pulling"}"#.to_vec()),
+            Ok(b"\n{\"sta".to_vec()),
+            Ok(br#"tus":"Done"}"#.to_vec()),
+        ];
+        let items: Vec<_> = json_lines::<Progress>(Box::pin(stream::iter(chunks)))
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+        assert_eq!(
+            items,
+            vec![
+                Progress {
+                    status: "Pulling".to_string()
+                },
+                Progress {
+                    status: "Done".to_string()
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use domain::size::ByteSize;
+use futures_util::future::{abortable, AbortHandle};
+use futures_util::stream::StreamExt;
+use std::collections::{HashMap, VecDeque};
+use std::sync::mpsc;
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Gauge, Paragraph, Sparkline},
+    Frame,
+};
+use usecase::monitor_stats::{ResourceUsageDto, ResourceUsageStream};
+
+/// Number of samples kept for the CPU sparkline, about one minute.
+const HISTORY_LEN: usize = 60;
+
+/// Follows the stats stream of each watched container on its own task and keeps the latest
+/// usage and a short CPU history per container. Every stream is cancelled on drop.
+pub struct StatsMonitor {
+    tx: mpsc::Sender<(String, Option<ResourceUsageDto>)>,
+    rx: mpsc::Receiver<(String, Option<ResourceUsageDto>)>,
+    watching: HashMap<String, AbortHandle>,
+    latest: HashMap<String, ResourceUsageDto>,
+    cpu_history: HashMap<String, VecDeque<u64>>,
+}
+
+impl StatsMonitor {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel();
+        Self {
+            tx,
+            rx,
+            watching: HashMap::new(),
+            latest: HashMap::new(),
+            cpu_history: HashMap::new(),
+        }
+    }
+
+    pub fn is_watching(&self, id: &str) -> bool {
+        self.watching.contains_key(id)
+    }
+
+    /// Follows the stream started for the container, unless it is already followed.
+    pub fn follow(&mut self, id: String, stream: ResourceUsageStream) {
+        if self.is_watching(&id) {
+            return;
+        }
+        let (task, abort_handle) = abortable(forward(id.clone(), stream, self.tx.clone()));
+        // It runs whenever the view awaits, which it does while waiting for the next event.
+        tokio::spawn(task);
+        self.watching.insert(id, abort_handle);
+    }
+
+    /// Applies every sample received since the last call. Meant to be called on each tick.
+    pub fn update(&mut self) {
+        while let Ok((id, usage)) = self.rx.try_recv() {
+            match usage {
+                Some(usage) => {
+                    let history = self.cpu_history.entry(id.clone()).or_default();
+                    if history.len() >= HISTORY_LEN {
+                        history.pop_front();
+                    }
+                    history.push_back(usage.cpu_percent.round() as u64);
+                    self.latest.insert(id, usage);
+                }
+                None => {
+                    self.watching.remove(&id);
+                    self.latest.remove(&id);
+                    self.cpu_history.remove(&id);
+                }
+            }
+        }
+    }
+
+    pub fn usage(&self, id: &str) -> Option<&ResourceUsageDto> {
+        self.latest.get(id)
+    }
+
+    pub fn render(&self, frame: &mut Frame<impl Backend>, rect: Rect, id: Option<&str>) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled("Resources", Style::default().fg(Color::DarkGray)));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+
+        let usage = match id.and_then(|id| self.latest.get(id)) {
+            Some(usage) => usage,
+            None => {
+                frame.render_widget(Paragraph::new("No resource usage for this container"), inner);
+                return;
+            }
+        };
+        let history: Vec<u64> = id
+            .and_then(|id| self.cpu_history.get(id))
+            .map(|h| h.iter().cloned().collect())
+            .unwrap_or_default();
+
+        let areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Min(1),
+                ]
+                .as_ref(),
+            )
+            .split(inner);
+        let cpu = Gauge::default()
+            .gauge_style(Style::default().fg(Color::Green))
+            .ratio((usage.cpu_percent / 100.0).clamp(0.0, 1.0))
+            .label(format!("CPU {:.2}%", usage.cpu_percent));
+        frame.render_widget(cpu, areas[0]);
+        let memory = Gauge::default()
+            .gauge_style(Style::default().fg(Color::Blue))
+            .ratio((usage.memory_percent / 100.0).clamp(0.0, 1.0))
+            .label(format!(
+                "MEM {:#} / {:#} ({:.2}%)",
+                usage.memory_used, usage.memory_limit, usage.memory_percent
+            ));
+        frame.render_widget(memory, areas[1]);
+        let io = format!(
+            "NET rx {}/s tx {}/s  BLOCK read {}/s write {}/s  PIDS {}",
+            ByteSize(usage.network_rx_rate as u64),
+            ByteSize(usage.network_tx_rate as u64),
+            ByteSize(usage.block_read_rate as u64),
+            ByteSize(usage.block_write_rate as u64),
+            usage.pids
+        );
+        frame.render_widget(Paragraph::new(io), areas[2]);
+        let max = history.iter().cloned().max().unwrap_or_default().max(100);
+        let sparkline = Sparkline::default()
+            .style(Style::default().fg(Color::Green))
+            .data(&history)
+            .max(max);
+        frame.render_widget(sparkline, areas[3]);
+    }
+}
+
+impl Default for StatsMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for StatsMonitor {
+    fn drop(&mut self) {
+        self.watching.values().for_each(|h| h.abort());
+    }
+}
+
+async fn forward(id: String, mut stream: ResourceUsageStream, tx: mpsc::Sender<(String, Option<ResourceUsageDto>)>) {
+    while let Some(Ok(usage)) = stream.next().await {
+        if tx.send((id.clone(), Some(usage))).is_err() {
+            return;
+        }
+    }
+    let _ = tx.send((id, None));
+}
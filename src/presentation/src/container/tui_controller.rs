@@ -1,16 +1,550 @@
-use crate::TuiResult;
-use usecase::list_containers::ListContainerUsecase;
+use crate::container::stats::StatsMonitor;
+use crate::events::feed::EventFeed;
+use crate::exec::tui_controller::ExecTuiController;
+use crate::log::tui_controller::LogTuiController;
+use crate::shared::{
+    event::{Event, Events},
+    layout,
+    loader::{Loader, Next},
+    paragraph::SimpleParagraph,
+    sort::{SortKey, Sorts},
+    spinner,
+    status_bar::StatusBar,
+    table::{StatefulTable, StatelessTable},
+    tabs::TabsState,
+};
+use crate::{TuiOperationStatus, TuiResult};
+use domain::container::{Container, ContainerSummary};
+use domain::event::EventKind;
+use futures_util::future::join_all;
+use std::error::Error;
+
+use termion::event::Key;
+use tui::{backend::Backend, layout::Constraint, Terminal};
+use usecase::{
+    inspect_container::{InspectContainerDto, InspectContainerUsecase, MountRecord},
+    list_containers::ListContainerUsecase,
+    monitor_stats::{MonitorStatsUsecase, ResourceUsageStream},
+    operate_container::{ContainerAction, OperateContainerUsecase},
+};
 
 pub struct ContainerTuiController<'a> {
     list_usecase: &'a ListContainerUsecase<'a>,
+    inspect_usecase: &'a InspectContainerUsecase<'a>,
+    operate_usecase: &'a OperateContainerUsecase<'a>,
+    stats_usecase: &'a MonitorStatsUsecase<'a>,
+    log_controller: &'a LogTuiController<'a>,
+    exec_controller: &'a ExecTuiController<'a>,
+    event_feed: &'a EventFeed,
+    sorts: &'a Sorts,
+}
+
+const CONTAINERS_TITLE: &str = "Containers";
+
+/// What the view loads in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Slot {
+    Containers,
+    Detail,
+    /// Starting the stats streams of the running containers.
+    Stats,
+    /// Running an action on a container, one at a time.
+    Action,
+}
+
+type StatsStart = (String, Result<ResourceUsageStream, Box<dyn Error + Send + Sync>>);
+
+enum Loaded {
+    Containers(Result<Vec<ContainerSummary>, String>),
+    /// The stats stream started for each container ID.
+    Stats(Vec<StatsStart>),
+    /// The details of the container with the ID.
+    Detail(String, Result<Box<InspectContainerDto>, String>),
+    /// The action run on the container with the ID, and the container afterwards unless it is gone.
+    Operated(String, ContainerAction, Result<Option<Box<Container>>, String>),
+}
+
+impl<'a> ContainerTuiController<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        list_usecase: &'a ListContainerUsecase<'a>,
+        inspect_usecase: &'a InspectContainerUsecase<'a>,
+        operate_usecase: &'a OperateContainerUsecase<'a>,
+        stats_usecase: &'a MonitorStatsUsecase<'a>,
+        log_controller: &'a LogTuiController<'a>,
+        exec_controller: &'a ExecTuiController<'a>,
+        event_feed: &'a EventFeed,
+        sorts: &'a Sorts,
+    ) -> Self {
+        Self {
+            list_usecase,
+            inspect_usecase,
+            operate_usecase,
+            stats_usecase,
+            log_controller,
+            exec_controller,
+            event_feed,
+            sorts,
+        }
+    }
+
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        tab: &mut TabsState<'a>,
+        events: &Events,
+    ) -> TuiResult {
+        let mut status_bar = StatusBar::new();
+        self.event_feed.update();
+        let mut revision = self.event_feed.revision(&EventKind::Container);
+        let mut loader = Loader::new();
+        self.load_containers(&mut loader);
+        let mut containers: Vec<ContainerSummary> = vec![];
+        // resource usage of running containers
+        let mut monitor = StatsMonitor::new();
+        // container list table
+        let header = vec!["NAME", "IMAGE", "STATE", "STATUS", "PORTS", "CPU%", "MEM", "CREATED"];
+        let widths = vec![
+            Constraint::Percentage(16),
+            Constraint::Percentage(16),
+            Constraint::Percentage(8),
+            Constraint::Percentage(13),
+            Constraint::Percentage(15),
+            Constraint::Percentage(7),
+            Constraint::Percentage(10),
+            Constraint::Percentage(15),
+        ];
+        let mut container_table = StatefulTable::new(vec![], CONTAINERS_TITLE, header, widths);
+        container_table.set_sort(self.sorts.get("Container"));
+        // container detail paragraph
+        let mut paragraph = SimpleParagraph::new("Detail", vec!["It shows container's details here"]);
+        // container mounts table
+        let header = vec!["TYPE", "SOURCE", "DESTINATION", "RW"];
+        let widths = vec![
+            Constraint::Percentage(10),
+            Constraint::Percentage(40),
+            Constraint::Percentage(40),
+            Constraint::Percentage(10),
+        ];
+        let mut mount_table = StatelessTable::new(vec![], "Mounts", header, widths);
+
+        loop {
+            // Reload the list whenever containers were e.g. created, removed, started or stopped.
+            self.event_feed.update();
+            if self.event_feed.revision(&EventKind::Container) != revision {
+                revision = self.event_feed.revision(&EventKind::Container);
+                self.load_containers(&mut loader);
+            }
+            monitor.update();
+            container_table.items = containers_to_table(&containers, &monitor);
+            container_table.sort_keys = containers_to_sort_keys(&containers, &monitor);
+            container_table.title = spinner::title(CONTAINERS_TITLE, loader.is_loading(Slot::Containers));
+            paragraph.title = spinner::title("Detail", loader.is_loading(Slot::Detail));
+            let selected_id = container_table
+                .selected()
+                .and_then(|v| containers.get(v))
+                .map(|c| c.id.as_str());
+
+            terminal.draw(|f| {
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_header_and_main(f);
+                let header = areas.0;
+                let main = areas.1;
+                tab.draw(f, header);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_off_bottom_rows(main, 1);
+                let main = areas.0;
+                let status = areas.1;
+                status_bar.render(f, status);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_horizontal_pains(main);
+                let left_pain = areas.0;
+                let right_pain = areas.1;
+                container_table.render(f, left_pain);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_off_top_rows(right_pain, 7);
+                let resources = areas.0;
+                let right_pain = areas.1;
+                monitor.render(f, resources, selected_id);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_vertical_pains(right_pain);
+                let detail_up = areas.0;
+                let detail_down = areas.1;
+                paragraph.render(f, detail_up);
+                mount_table.render(f, detail_down);
+            })?;
+
+            // The stats tasks share the runtime's single thread, so they only collect samples while this
+            // awaits. Each tick then shows what they collected.
+            let key = match loader.next(events).await? {
+                Next::Event(Event::Input(key)) => key,
+                Next::Event(Event::Tick) => continue,
+                Next::Loaded(Loaded::Containers(Ok(loaded))) => {
+                    let previous = container_table
+                        .selected()
+                        .and_then(|v| containers.get(v))
+                        .map(|c| c.id.clone());
+                    containers = loaded;
+                    self.watch_running(&mut loader, &containers, &monitor);
+                    let selected = previous
+                        .as_ref()
+                        .and_then(|id| containers.iter().position(|c| c.id == *id));
+                    container_table.select(selected);
+                    match selected.and_then(|v| containers.get(v)) {
+                        Some(container) => self.load_detail(&mut loader, container.id.clone()),
+                        None if previous.is_some() => {
+                            loader.cancel(Slot::Detail);
+                            paragraph.texts = vec!["It shows container's details here".to_string()];
+                            mount_table.items = vec![];
+                        }
+                        None => {}
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Containers(Err(e))) => {
+                    status_bar.error(format!("Failed to list containers: {}", e));
+                    continue;
+                }
+                Next::Loaded(Loaded::Stats(streams)) => {
+                    // A container which can't be monitored simply shows no usage.
+                    for (id, stream) in streams {
+                        if let Ok(stream) = stream {
+                            monitor.follow(id, stream);
+                        }
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Detail(id, detail)) => {
+                    // The list may have been reloaded with another container at the selected row meanwhile.
+                    let selected = container_table.selected().and_then(|v| containers.get(v));
+                    if matches!(selected, Some(c) if c.id == id) {
+                        let detail = gen_detail_text(detail);
+                        paragraph.texts = detail.0;
+                        mount_table.items = detail.1;
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Operated(id, action, result)) => {
+                    let idx = containers.iter().position(|c| c.id == id);
+                    match (result, idx) {
+                        (Ok(Some(container)), Some(idx)) => {
+                            let summary = &mut containers[idx];
+                            summary.update_state(&container.state);
+                            status_bar.info(format!("{}: {}", action.name(), summary.name()));
+                            self.watch_running(&mut loader, &containers, &monitor);
+                            if container_table.selected() == Some(idx) {
+                                self.load_detail(&mut loader, id);
+                            }
+                        }
+                        (Ok(None), Some(idx)) => {
+                            // e.g. removed by `--rm` once stopped, so its row must go as well.
+                            let selected = container_table.selected();
+                            let previous = selected.and_then(|v| containers.get(v)).map(|c| c.id.clone());
+                            let gone = containers.remove(idx);
+                            status_bar.error(format!("Container {} no longer exists", gone.name()));
+                            let selected = match previous {
+                                Some(previous) if previous != id => containers.iter().position(|c| c.id == previous),
+                                _ => selected.filter(|v| *v < containers.len()),
+                            };
+                            container_table.select(selected);
+                            match selected.and_then(|v| containers.get(v)) {
+                                Some(container) => self.load_detail(&mut loader, container.id.clone()),
+                                None => {
+                                    loader.cancel(Slot::Detail);
+                                    paragraph.texts = vec!["It shows container's details here".to_string()];
+                                    mount_table.items = vec![];
+                                }
+                            }
+                        }
+                        // The list was reloaded without the container meanwhile.
+                        (Ok(_), None) => status_bar.info(format!("{}: {}", action.name(), id)),
+                        (Err(e), _) => status_bar.error(format!("Failed to {} container: {}", action.name(), e)),
+                    }
+                    continue;
+                }
+            };
+            if container_table.is_searching() {
+                if container_table.handle_search_key(key) {
+                    match container_table.selected().and_then(|v| containers.get(v)) {
+                        Some(container) => self.load_detail(&mut loader, container.id.clone()),
+                        None => {
+                            loader.cancel(Slot::Detail);
+                            paragraph.texts = vec!["It shows container's details here".to_string()];
+                            mount_table.items = vec![];
+                        }
+                    }
+                }
+                if !container_table.is_searching() {
+                    events.enable_exit_key();
+                }
+                continue;
+            }
+            match key {
+                Key::Char('q') => {
+                    break;
+                }
+                Key::Char('/') => {
+                    events.disable_exit_key();
+                    container_table.start_search();
+                }
+                Key::Char('o') => {
+                    container_table.cycle_sort();
+                    self.sorts.set("Container", container_table.sort());
+                }
+                Key::Down | Key::Up => {
+                    if key == Key::Down {
+                        container_table.next();
+                    } else {
+                        container_table.previous();
+                    }
+                    match container_table.selected().and_then(|v| containers.get(v)) {
+                        Some(container) => self.load_detail(&mut loader, container.id.clone()),
+                        None => {
+                            loader.cancel(Slot::Detail);
+                            paragraph.texts = vec!["It shows container's details here".to_string()];
+                            mount_table.items = vec![];
+                        }
+                    }
+                }
+                Key::Char('l') => {
+                    if let Some(container) = container_table.selected().and_then(|v| containers.get(v)) {
+                        let status = self
+                            .log_controller
+                            .draw(terminal, events, &container.id, container.name())
+                            .await?;
+                        if status == TuiOperationStatus::Quit {
+                            return Ok(status);
+                        }
+                    }
+                }
+                Key::Char('e') => {
+                    let selected = container_table.selected();
+                    if let Some(container) = selected.and_then(|v| containers.get(v)) {
+                        let status = self
+                            .exec_controller
+                            .draw(terminal, events, &container.id, container.name())
+                            .await?;
+                        if status == TuiOperationStatus::Quit {
+                            return Ok(status);
+                        }
+                    }
+                }
+                Key::Char(c) if key_to_action(c).is_some() && loader.is_loading(Slot::Action) => {
+                    status_bar.info("Wait for the running action to finish");
+                }
+                Key::Char(c) => {
+                    let selected = container_table.selected().and_then(|v| containers.get(v));
+                    if let (Some(action), Some(container)) = (key_to_action(c), selected) {
+                        status_bar.info(format!("{}: {}...", action.name(), container.name()));
+                        self.operate(&mut loader, container.id.clone(), action);
+                    }
+                }
+                Key::Right => {
+                    tab.next();
+                    return Ok(TuiOperationStatus::Continue);
+                }
+                Key::Left => {
+                    tab.previous();
+                    return Ok(TuiOperationStatus::Continue);
+                }
+                _ => {}
+            }
+        }
+        Ok(TuiOperationStatus::Quit)
+    }
+
+    fn load_containers(&self, loader: &mut Loader<'a, Slot, Loaded>) {
+        let usecase = self.list_usecase;
+        loader.load(Slot::Containers, async move {
+            Loaded::Containers(usecase.list_container().await.map_err(|e| e.to_string()))
+        });
+    }
+
+    /// Starts the stats streams of the running containers not watched yet, all at once so that a daemon
+    /// slow to answer for one container doesn't hold back the others.
+    fn watch_running(
+        &self,
+        loader: &mut Loader<'a, Slot, Loaded>,
+        containers: &[ContainerSummary],
+        monitor: &StatsMonitor,
+    ) {
+        let ids: Vec<String> = containers
+            .iter()
+            .filter(|c| c.state == "running" && !monitor.is_watching(&c.id))
+            .map(|c| c.id.clone())
+            .collect();
+        if ids.is_empty() {
+            return;
+        }
+        let usecase = self.stats_usecase;
+        loader.load(Slot::Stats, async move {
+            let streams = ids.into_iter().map(|id| async move {
+                let stream = usecase.monitor_stats(id.as_str()).await;
+                (id, stream)
+            });
+            Loaded::Stats(join_all(streams).await)
+        });
+    }
+
+    fn load_detail(&self, loader: &mut Loader<'a, Slot, Loaded>, id: String) {
+        let usecase = self.inspect_usecase;
+        loader.load(Slot::Detail, async move {
+            let detail = usecase
+                .inspect_container(&id)
+                .await
+                .map(Box::new)
+                .map_err(|e| e.to_string());
+            Loaded::Detail(id, detail)
+        });
+    }
+
+    fn operate(&self, loader: &mut Loader<'a, Slot, Loaded>, id: String, action: ContainerAction) {
+        let usecase = self.operate_usecase;
+        loader.load(Slot::Action, async move {
+            let result = usecase
+                .operate_container(id.as_str(), action)
+                .await
+                .map(|container| container.map(Box::new))
+                .map_err(|e| e.to_string());
+            Loaded::Operated(id, action, result)
+        });
+    }
 }
 
-impl<'a> ContainerTuiController {
-    pub fn new(list_usecase: &'a ListContainerUsecase<'a>) -> Self {
-        Self { list_usecase }
+fn key_to_action(key: char) -> Option<ContainerAction> {
+    match key {
+        's' => Some(ContainerAction::Start),
+        't' => Some(ContainerAction::Stop),
+        'r' => Some(ContainerAction::Restart),
+        'k' => Some(ContainerAction::Kill),
+        'p' => Some(ContainerAction::Pause),
+        'u' => Some(ContainerAction::Unpause),
+        _ => None,
     }
+}
+
+fn containers_to_table(containers: &[ContainerSummary], monitor: &StatsMonitor) -> Vec<Vec<String>> {
+    containers.iter().map(|c| container_to_row(c, monitor)).collect()
+}
+
+fn container_to_row(c: &ContainerSummary, monitor: &StatsMonitor) -> Vec<String> {
+    let usage = monitor.usage(&c.id);
+    vec![
+        c.name().to_string(),
+        c.image.clone(),
+        c.state.clone(),
+        c.status.clone(),
+        format_ports(c),
+        usage.map_or("-".to_string(), |u| format!("{:.2}%", u.cpu_percent)),
+        usage.map_or("-".to_string(), |u| format!("{:#}", u.memory_used)),
+        c.created.format("%Y-%m-%d %H:%M").to_string(),
+    ]
+}
+
+fn containers_to_sort_keys(containers: &[ContainerSummary], monitor: &StatsMonitor) -> Vec<Vec<SortKey>> {
+    containers
+        .iter()
+        .map(|c| {
+            let usage = monitor.usage(&c.id);
+            vec![
+                SortKey::text(c.name()),
+                SortKey::text(&c.image),
+                SortKey::text(&c.state),
+                SortKey::text(&c.status),
+                SortKey::text(format_ports(c)),
+                usage.map_or(SortKey::Missing, |u| SortKey::Float(u.cpu_percent)),
+                usage.map(|u| u.memory_used).into(),
+                SortKey::Integer(c.created.timestamp()),
+            ]
+        })
+        .collect()
+}
+
+fn format_ports(c: &ContainerSummary) -> String {
+    c.ports
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn gen_detail_text(detail: Result<Box<InspectContainerDto>, String>) -> (Vec<String>, Vec<Vec<String>>) {
+    match detail {
+        Ok(v) => (format_detail_text(&v), format_mount_text(v.mounts)),
+        Err(e) => (vec![format!("Failed to get container's details: {}", e)], vec![]),
+    }
+}
 
-    pub async fn draw(&self, terminal: &mut Terminal<impl Backend>, events: &Events) -> TuiResult {
-        unimplemented!();
+fn format_detail_text(detail: &InspectContainerDto) -> Vec<String> {
+    let mut texts = Vec::new();
+    texts.push(format!("id: {}", detail.id));
+    texts.push(format!("name: {}", detail.name));
+    texts.push(format!("image: {}", detail.image));
+    texts.push(format!("created: {}", detail.created.format("%Y-%m-%d %H:%M:%S")));
+    texts.push(format!("status: {}", detail.status));
+    texts.push(format!("pid: {}", detail.pid));
+    texts.push(format!("exit code: {}", detail.exit_code));
+    if detail.oom_killed {
+        texts.push("oom killed: true".to_string());
     }
+    if !detail.error.is_empty() {
+        texts.push(format!("error: {}", detail.error));
+    }
+    texts.push(format!("started at: {}", detail.started_at));
+    texts.push(format!("finished at: {}", detail.finished_at));
+    if let Some(health) = &detail.health {
+        texts.push(format!(
+            "health: {} (failing streak: {})",
+            health.status, health.failing_streak
+        ));
+        if let Some(output) = &health.last_output {
+            texts.push(format!("- {}", output.trim()));
+        }
+    }
+    texts.push(format!("restart policy: {}", detail.restart_policy));
+    texts.push(format!("network mode: {}", detail.network_mode));
+    texts.push(format!("privileged: {}", detail.privileged));
+    texts.push(format!("hostname: {}", detail.hostname));
+    texts.push(format!("user: {}", detail.user));
+    texts.push(format!("working dir: {}", detail.working_dir));
+    texts.push(format!("entrypoint: {:?}", detail.entrypoint));
+    texts.push(format!("cmd: {:?}", detail.cmd));
+
+    texts.push("ports: ".to_string());
+    detail.ports.iter().for_each(|v| texts.push(format!("- {}", v)));
+
+    texts.push("networks: ".to_string());
+    for network in detail.networks.iter() {
+        texts.push(format!(
+            "- {}: {} (gateway: {})",
+            network.name, network.ip_address, network.gateway
+        ));
+    }
+
+    texts.push("environment variables: ".to_string());
+    detail
+        .environment_variables
+        .iter()
+        .for_each(|v| texts.push(format!("- {}", v)));
+
+    texts.push("labels: ".to_string());
+    for (k, v) in detail.labels.iter() {
+        texts.push(format!("- {}: {}", k, v));
+    }
+
+    texts
+}
+
+fn format_mount_text(mounts: Vec<MountRecord>) -> Vec<Vec<String>> {
+    mounts
+        .into_iter()
+        .map(|m| {
+            vec![
+                m.mount_type,
+                m.source,
+                m.destination,
+                if m.read_write { "rw" } else { "ro" }.to_string(),
+            ]
+        })
+        .collect()
 }
@@ -0,0 +1,130 @@
+use crate::shared::{
+    event::{Event, Events},
+    layout,
+    paragraph::SimpleParagraph,
+    table::StatefulTable,
+    tabs::TabsState,
+};
+use crate::{TuiOperationStatus, TuiResult};
+use domain::context::Context;
+
+use termion::event::Key;
+use tui::{backend::Backend, layout::Constraint, Terminal};
+use usecase::list_contexts::ListContextsUsecase;
+
+pub struct ContextTuiController<'a> {
+    list_usecase: &'a ListContextsUsecase<'a>,
+    /// Context harpoon is connected to, which may differ from the docker CLI's current one.
+    active: &'a str,
+    notice: Option<String>,
+}
+
+impl<'a> ContextTuiController<'a> {
+    pub fn new(list_usecase: &'a ListContextsUsecase<'a>, active: &'a str, notice: Option<String>) -> Self {
+        Self {
+            list_usecase,
+            active,
+            notice,
+        }
+    }
+
+    /// Lists the docker CLI's contexts; `Enter` asks the caller to reconnect to the selected one.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        tab: &mut TabsState<'a>,
+        events: &Events,
+    ) -> TuiResult {
+        let (contexts, mut texts) = match self.list_usecase.list_contexts().await {
+            Ok(contexts) => (contexts, vec![]),
+            Err(e) => (vec![], vec![format!("Failed to read contexts: {}", e)]),
+        };
+        texts.insert(0, format!("connected to: {}", self.active));
+        if let Some(notice) = &self.notice {
+            texts.push(notice.clone());
+        }
+        // context list table
+        let items = contexts_to_table(&contexts, self.active);
+        let header = vec!["", "NAME", "DESCRIPTION", "DOCKER ENDPOINT"];
+        let widths = vec![
+            Constraint::Length(1),
+            Constraint::Percentage(20),
+            Constraint::Percentage(40),
+            Constraint::Percentage(38),
+        ];
+        let mut context_table = StatefulTable::new(items, "Contexts (Enter: switch)", header, widths);
+        let paragraph = SimpleParagraph::new("Connection", texts);
+
+        loop {
+            terminal.draw(|f| {
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_header_and_main(f);
+                let header = areas.0;
+                let main = areas.1;
+                tab.draw(f, header);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_vertical_pains(main);
+                let up = areas.0;
+                let down = areas.1;
+                context_table.render(f, up);
+                paragraph.render(f, down);
+            })?;
+
+            if let Event::Input(key) = events.next_async().await? {
+                if context_table.is_searching() {
+                    context_table.handle_search_key(key);
+                    if !context_table.is_searching() {
+                        events.enable_exit_key();
+                    }
+                    continue;
+                }
+                match key {
+                    Key::Char('q') => {
+                        break;
+                    }
+                    Key::Char('/') => {
+                        events.disable_exit_key();
+                        context_table.start_search();
+                    }
+                    Key::Down if !contexts.is_empty() => context_table.next(),
+                    Key::Up if !contexts.is_empty() => context_table.previous(),
+                    Key::Char('\n') => {
+                        if let Some(context) = context_table.selected().and_then(|v| contexts.get(v)) {
+                            if context.name != self.active {
+                                return Ok(TuiOperationStatus::SwitchContext(context.name.clone()));
+                            }
+                        }
+                    }
+                    Key::Right => {
+                        tab.next();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    Key::Left => {
+                        tab.previous();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    _ => {}
+                }
+            };
+        }
+        Ok(TuiOperationStatus::Quit)
+    }
+}
+
+fn contexts_to_table(contexts: &[Context], active: &str) -> Vec<Vec<String>> {
+    contexts
+        .iter()
+        .map(|c| {
+            vec![
+                if c.name == active { "*" } else { "" }.to_string(),
+                if c.current {
+                    format!("{} (current)", c.name)
+                } else {
+                    c.name.clone()
+                },
+                c.description.clone(),
+                c.host.clone(),
+            ]
+        })
+        .collect()
+}
@@ -0,0 +1,131 @@
+use domain::event::{EventKind, EventStream, SystemEvent};
+use futures_util::future::{abortable, AbortHandle};
+use futures_util::stream::StreamExt;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::sync::mpsc::{self, TryRecvError};
+use usecase::watch_events::WatchEventsUsecase;
+
+/// Number of events kept for the timeline.
+const HISTORY_CAPACITY: usize = 1_000;
+
+/// Follows the daemon's events on its own task for as long as the connection lives. The Events tab shows
+/// the history and the other tabs reload their lists when an event concerns them. The stream is cancelled
+/// on drop.
+///
+/// The task shares the runtime's single thread, so every view has to await its events for it to run.
+pub struct EventFeed {
+    rx: mpsc::Receiver<Result<SystemEvent, String>>,
+    abort_handle: Option<AbortHandle>,
+    state: RefCell<FeedState>,
+}
+
+#[derive(Default)]
+struct FeedState {
+    /// Oldest first.
+    history: VecDeque<SystemEvent>,
+    /// Number of events so far which changed what the list of each kind shows.
+    revisions: HashMap<EventKind, u64>,
+    /// Number of events of each kind received so far, including those dropped from the history.
+    received: HashMap<EventKind, u64>,
+    error: Option<String>,
+}
+
+impl EventFeed {
+    pub async fn start(usecase: &WatchEventsUsecase<'_>) -> Self {
+        let (tx, rx) = mpsc::channel();
+        let mut state = FeedState::default();
+        let abort_handle = match usecase.watch_events().await {
+            Ok(stream) => {
+                let (task, abort_handle) = abortable(forward(stream, tx));
+                tokio::spawn(task);
+                Some(abort_handle)
+            }
+            Err(e) => {
+                state.error = Some(format!("Failed to watch events: {}", e));
+                None
+            }
+        };
+        Self {
+            rx,
+            abort_handle,
+            state: RefCell::new(state),
+        }
+    }
+
+    /// Applies every event received since the last call. Meant to be called on each tick by whichever
+    /// view is shown.
+    pub fn update(&self) {
+        let mut state = self.state.borrow_mut();
+        loop {
+            match self.rx.try_recv() {
+                Ok(Ok(event)) => {
+                    *state.received.entry(event.kind.clone()).or_default() += 1;
+                    if event.alters_listing() {
+                        *state.revisions.entry(event.kind.clone()).or_default() += 1;
+                    }
+                    if state.history.len() >= HISTORY_CAPACITY {
+                        state.history.pop_front();
+                    }
+                    state.history.push_back(event);
+                }
+                Ok(Err(e)) => state.error = Some(format!("Event stream failed: {}", e)),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    if state.error.is_none() && self.abort_handle.is_some() {
+                        state.error = Some("The daemon closed the event stream".to_string());
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Changes whenever objects of the kind were e.g. created, removed or changed their state, so a
+    /// view can tell whether to reload by comparing it with the revision it last loaded.
+    pub fn revision(&self, kind: &EventKind) -> u64 {
+        self.state.borrow().revisions.get(kind).copied().unwrap_or_default()
+    }
+
+    /// Number of events of the kind, or of all kinds, received so far. It grows by the number of events
+    /// `recent` gained at the front, even once the oldest ones are dropped.
+    pub fn received(&self, kind: Option<&EventKind>) -> u64 {
+        let state = self.state.borrow();
+        match kind {
+            Some(kind) => state.received.get(kind).copied().unwrap_or_default(),
+            None => state.received.values().sum(),
+        }
+    }
+
+    /// Events of the kind, or all of them, newest first.
+    pub fn recent(&self, kind: Option<&EventKind>) -> Vec<SystemEvent> {
+        self.state
+            .borrow()
+            .history
+            .iter()
+            .rev()
+            .filter(|event| kind.map_or(true, |kind| event.kind == *kind))
+            .cloned()
+            .collect()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.state.borrow().error.clone()
+    }
+}
+
+impl Drop for EventFeed {
+    fn drop(&mut self) {
+        if let Some(abort_handle) = &self.abort_handle {
+            abort_handle.abort();
+        }
+    }
+}
+
+async fn forward(mut stream: EventStream, tx: mpsc::Sender<Result<SystemEvent, String>>) {
+    while let Some(item) = stream.next().await {
+        if tx.send(item.map_err(|e| e.to_string())).is_err() {
+            break;
+        }
+    }
+}
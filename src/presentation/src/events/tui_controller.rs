@@ -0,0 +1,163 @@
+use crate::events::feed::EventFeed;
+use crate::shared::{
+    event::{Event, Events},
+    layout,
+    paragraph::SimpleParagraph,
+    status_bar::StatusBar,
+    table::StatefulTable,
+    tabs::TabsState,
+};
+use crate::{TuiOperationStatus, TuiResult};
+use domain::event::{EventKind, SystemEvent};
+
+use termion::event::Key;
+use tui::{backend::Backend, layout::Constraint, Terminal};
+
+/// Kinds `f` cycles through; `None` shows every event.
+const FILTERS: [Option<EventKind>; 5] = [
+    None,
+    Some(EventKind::Container),
+    Some(EventKind::Image),
+    Some(EventKind::Volume),
+    Some(EventKind::Network),
+];
+
+pub struct EventTuiController<'a> {
+    feed: &'a EventFeed,
+}
+
+impl<'a> EventTuiController<'a> {
+    pub fn new(feed: &'a EventFeed) -> Self {
+        Self { feed }
+    }
+
+    /// Shows the daemon's events as a timeline, newest first, with the attributes of the selected one.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        tab: &mut TabsState<'a>,
+        events: &Events,
+    ) -> TuiResult {
+        let mut status_bar = StatusBar::new();
+        let mut filter = 0;
+        // event timeline table
+        let header = vec!["TIME", "TYPE", "ACTION", "NAME"];
+        let widths = vec![
+            Constraint::Percentage(25),
+            Constraint::Percentage(15),
+            Constraint::Percentage(25),
+            Constraint::Percentage(35),
+        ];
+        let mut event_table = StatefulTable::new(vec![], "", header, widths);
+        // event detail paragraph
+        let mut paragraph = SimpleParagraph::new("Detail", vec!["It shows event's attributes here"]);
+        // Events shown so far, by what the feed had received.
+        let mut shown = 0;
+
+        loop {
+            self.feed.update();
+            if let Some(error) = self.feed.error() {
+                status_bar.error(error);
+            }
+            let timeline = self.feed.recent(FILTERS[filter].as_ref());
+            let received = self.feed.received(FILTERS[filter].as_ref());
+            // Keep the selection on the same event while new ones are prepended. The length of the timeline
+            // stops growing once the oldest events are dropped, so count what arrived instead.
+            if let Some(selected) = event_table.selected() {
+                let shift = (received - shown) as usize;
+                event_table.select(Some((selected + shift).min(timeline.len().saturating_sub(1))));
+            }
+            shown = received;
+            event_table.items = timeline.iter().map(event_to_row).collect();
+            event_table.title = format!(
+                "Events [{}] (f: filter)",
+                FILTERS[filter].as_ref().map_or("all", |kind| kind.name())
+            );
+            paragraph.texts = match event_table.selected().and_then(|v| timeline.get(v)) {
+                Some(event) => format_detail_text(event),
+                None => vec!["It shows event's attributes here".to_string()],
+            };
+
+            terminal.draw(|f| {
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_header_and_main(f);
+                let header = areas.0;
+                let main = areas.1;
+                tab.draw(f, header);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_off_bottom_rows(main, 1);
+                let main = areas.0;
+                let status = areas.1;
+                status_bar.render(f, status);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_horizontal_pains(main);
+                let left_pain = areas.0;
+                let right_pain = areas.1;
+                event_table.render(f, left_pain);
+                paragraph.render(f, right_pain);
+            })?;
+
+            if let Event::Input(key) = events.next_async().await? {
+                if event_table.is_searching() {
+                    event_table.handle_search_key(key);
+                    if !event_table.is_searching() {
+                        events.enable_exit_key();
+                    }
+                    continue;
+                }
+                match key {
+                    Key::Char('q') => {
+                        break;
+                    }
+                    Key::Char('/') => {
+                        events.disable_exit_key();
+                        event_table.start_search();
+                    }
+                    Key::Down => event_table.next(),
+                    Key::Up => event_table.previous(),
+                    Key::Char('f') => {
+                        filter = (filter + 1) % FILTERS.len();
+                        event_table.items.clear();
+                        event_table.select(None);
+                    }
+                    Key::Right => {
+                        tab.next();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    Key::Left => {
+                        tab.previous();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    _ => {}
+                }
+            };
+        }
+        Ok(TuiOperationStatus::Quit)
+    }
+}
+
+fn event_to_row(event: &SystemEvent) -> Vec<String> {
+    vec![
+        event.time.format("%Y-%m-%d %H:%M:%S").to_string(),
+        event.kind.name().to_string(),
+        event.action.clone(),
+        event.actor_name().to_string(),
+    ]
+}
+
+fn format_detail_text(event: &SystemEvent) -> Vec<String> {
+    let mut texts = Vec::new();
+    texts.push(format!("type: {}", event.kind.name()));
+    texts.push(format!("action: {}", event.action));
+    texts.push(format!("id: {}", event.actor_id));
+    texts.push(format!("time: {}", event.time.format("%Y-%m-%d %H:%M:%S%.3f")));
+
+    texts.push("attributes: ".to_string());
+    let mut attributes: Vec<_> = event.attributes.iter().collect();
+    attributes.sort();
+    for (k, v) in attributes {
+        texts.push(format!("- {}: {}", k, v));
+    }
+
+    texts
+}
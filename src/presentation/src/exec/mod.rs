@@ -0,0 +1,2 @@
+pub mod pseudo_terminal;
+pub mod tui_controller;
@@ -0,0 +1,115 @@
+use termion::event::Key;
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+/// Draws the screen of a `vt100::Parser` cell by cell.
+pub struct PseudoTerminal<'a> {
+    screen: &'a vt100::Screen,
+}
+
+impl<'a> PseudoTerminal<'a> {
+    pub fn new(screen: &'a vt100::Screen) -> Self {
+        Self { screen }
+    }
+}
+
+impl<'a> Widget for PseudoTerminal<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for row in 0..area.height {
+            for col in 0..area.width {
+                let cell = match self.screen.cell(row, col) {
+                    Some(cell) => cell,
+                    None => continue,
+                };
+                if cell.is_wide_continuation() {
+                    continue;
+                }
+                let contents = cell.contents();
+                let symbol = if contents.is_empty() { " " } else { contents.as_str() };
+                buf.get_mut(area.x + col, area.y + row)
+                    .set_symbol(symbol)
+                    .set_style(to_style(cell));
+            }
+        }
+    }
+}
+
+fn to_style(cell: &vt100::Cell) -> Style {
+    let mut style = Style::default()
+        .fg(to_color(cell.fgcolor()))
+        .bg(to_color(cell.bgcolor()));
+    if cell.bold() {
+        style = style.add_modifier(Modifier::BOLD);
+    }
+    if cell.italic() {
+        style = style.add_modifier(Modifier::ITALIC);
+    }
+    if cell.underline() {
+        style = style.add_modifier(Modifier::UNDERLINED);
+    }
+    if cell.inverse() {
+        style = style.add_modifier(Modifier::REVERSED);
+    }
+    style
+}
+
+fn to_color(color: vt100::Color) -> Color {
+    match color {
+        vt100::Color::Default => Color::Reset,
+        vt100::Color::Idx(i) => Color::Indexed(i),
+        vt100::Color::Rgb(r, g, b) => Color::Rgb(r, g, b),
+    }
+}
+
+/// Translates a key read by termion back into the bytes a terminal would have sent for it.
+pub fn key_to_bytes(key: Key, application_cursor: bool) -> Vec<u8> {
+    let cursor = |c: char| {
+        if application_cursor {
+            format!("\x1bO{}", c).into_bytes()
+        } else {
+            format!("\x1b[{}", c).into_bytes()
+        }
+    };
+    match key {
+        Key::Char(c) => {
+            let mut buf = [0; 4];
+            c.encode_utf8(&mut buf).as_bytes().to_vec()
+        }
+        Key::Ctrl(c) => vec![(c.to_ascii_lowercase() as u8) & 0x1f],
+        Key::Alt(c) => {
+            let mut bytes = vec![0x1b];
+            let mut buf = [0; 4];
+            bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            bytes
+        }
+        Key::Backspace => vec![0x7f],
+        Key::Esc => vec![0x1b],
+        Key::Up => cursor('A'),
+        Key::Down => cursor('B'),
+        Key::Right => cursor('C'),
+        Key::Left => cursor('D'),
+        Key::Home => b"\x1b[H".to_vec(),
+        Key::End => b"\x1b[F".to_vec(),
+        Key::Insert => b"\x1b[2~".to_vec(),
+        Key::Delete => b"\x1b[3~".to_vec(),
+        Key::PageUp => b"\x1b[5~".to_vec(),
+        Key::PageDown => b"\x1b[6~".to_vec(),
+        Key::BackTab => b"\x1b[Z".to_vec(),
+        Key::F(n) => match n {
+            1 => b"\x1bOP".to_vec(),
+            2 => b"\x1bOQ".to_vec(),
+            3 => b"\x1bOR".to_vec(),
+            4 => b"\x1bOS".to_vec(),
+            5 => b"\x1b[15~".to_vec(),
+            6..=10 => format!("\x1b[{}~", n + 11).into_bytes(),
+            11 | 12 => format!("\x1b[{}~", n + 12).into_bytes(),
+            _ => vec![],
+        },
+        Key::Null => vec![0],
+        _ => vec![],
+    }
+}
@@ -0,0 +1,125 @@
+use crate::exec::pseudo_terminal::{self, PseudoTerminal};
+use crate::shared::event::{Event, Events};
+use crate::{TuiOperationStatus, TuiResult};
+
+use futures_util::future::{select, Either};
+use futures_util::stream::StreamExt;
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Paragraph},
+    Terminal,
+};
+use usecase::exec_container::ExecContainerUsecase;
+
+pub struct ExecTuiController<'a> {
+    exec_usecase: &'a ExecContainerUsecase<'a>,
+}
+
+impl<'a> ExecTuiController<'a> {
+    pub fn new(exec_usecase: &'a ExecContainerUsecase<'a>) -> Self {
+        Self { exec_usecase }
+    }
+
+    /// Runs a shell in the container full-screen. Every key goes to the shell, except `Ctrl-q`
+    /// which detaches; the view also goes back to the caller once the shell exits.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        events: &Events,
+        id: &str,
+        name: &str,
+    ) -> TuiResult {
+        let mut session = match self.exec_usecase.exec_container(id, vec!["sh".to_string()]).await {
+            Ok(session) => session,
+            Err(e) => return show_error(terminal, events, &format!("Failed to exec into {}: {}", name, e)).await,
+        };
+
+        let mut size = pty_size(terminal.size()?);
+        let mut parser = vt100::Parser::new(size.height, size.width, 0);
+        // The shell still works at the daemon's default size if resizing fails.
+        let _ = self.exec_usecase.resize(&session.id, size.width, size.height).await;
+
+        events.disable_exit_key();
+        let title = format!("Exec: {} (Ctrl-q: detach)", name);
+        let result = loop {
+            let current = match terminal.size() {
+                Ok(area) => pty_size(area),
+                Err(e) => break Err(e.into()),
+            };
+            if current != size {
+                size = current;
+                parser.set_size(size.height, size.width);
+                let _ = self.exec_usecase.resize(&session.id, size.width, size.height).await;
+            }
+
+            let drawn = terminal.draw(|f| {
+                let block = Block::default()
+                    .borders(Borders::ALL)
+                    .title(Span::styled(title.as_str(), Style::default().fg(Color::DarkGray)));
+                let inner = block.inner(f.size());
+                f.render_widget(block, f.size());
+                let screen = parser.screen();
+                f.render_widget(PseudoTerminal::new(screen), inner);
+                if !screen.hide_cursor() {
+                    let (row, col) = screen.cursor_position();
+                    f.set_cursor(inner.x + col, inner.y + row);
+                }
+            });
+            if let Err(e) = drawn {
+                break Err(e.into());
+            }
+
+            // Waiting for the shell's output and for keys together also lets the task writing the keys to
+            // the shell run, as the runtime has a single thread.
+            match select(events.next_async(), session.output.next()).await {
+                Either::Left((Ok(Event::Input(Key::Ctrl('q'))), _)) => break Ok(TuiOperationStatus::Continue),
+                Either::Left((Ok(Event::Input(key)), _)) => {
+                    let bytes = pseudo_terminal::key_to_bytes(key, parser.screen().application_cursor());
+                    if !bytes.is_empty() && session.input.write(bytes).is_err() {
+                        break Ok(TuiOperationStatus::Continue);
+                    }
+                }
+                Either::Left((Ok(Event::Tick), _)) => {}
+                Either::Left((Err(e), _)) => break Err(e.into()),
+                Either::Right((Some(Ok(bytes)), _)) => parser.process(&bytes),
+                // The shell exited.
+                Either::Right(_) => break Ok(TuiOperationStatus::Continue),
+            }
+        };
+        events.enable_exit_key();
+
+        // Dropping the session closes the connection, which ends the shell if it is still running.
+        result
+    }
+}
+
+/// Size of the pane inside the borders.
+fn pty_size(area: Rect) -> Rect {
+    Rect::new(
+        0,
+        0,
+        area.width.saturating_sub(2).max(1),
+        area.height.saturating_sub(2).max(1),
+    )
+}
+
+async fn show_error(terminal: &mut Terminal<impl Backend>, events: &Events, message: &str) -> TuiResult {
+    terminal.draw(|f| {
+        let block = Block::default().borders(Borders::ALL).title(Span::styled(
+            "Exec (press any key)",
+            Style::default().fg(Color::DarkGray),
+        ));
+        f.render_widget(Paragraph::new(message).block(block), f.size());
+    })?;
+    loop {
+        match events.next_async().await? {
+            Event::Input(Key::Char('q')) => return Ok(TuiOperationStatus::Quit),
+            Event::Input(_) => return Ok(TuiOperationStatus::Continue),
+            Event::Tick => {}
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod pull;
 pub mod tui_controller;
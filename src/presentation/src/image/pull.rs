@@ -0,0 +1,115 @@
+use domain::image::{PullProgress, PullStream};
+use futures_util::future::{abortable, AbortHandle};
+use futures_util::stream::StreamExt;
+use std::sync::mpsc::{self, TryRecvError};
+use tui::{
+    backend::Backend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::Span,
+    widgets::{Block, Borders, Gauge, Paragraph},
+    Frame,
+};
+use usecase::pull_image::{PullImageUsecase, PullStatus};
+
+/// Follows a pull on its own task and shows a progress bar per layer. Dropping it cancels the pull.
+pub struct PullDialog {
+    pub reference: String,
+    status: PullStatus,
+    rx: mpsc::Receiver<Result<PullProgress, String>>,
+    abort_handle: AbortHandle,
+    error: Option<String>,
+}
+
+impl PullDialog {
+    pub async fn start(usecase: &PullImageUsecase<'_>, reference: &str) -> Result<Self, String> {
+        let stream = usecase.pull_image(reference).await.map_err(|e| e.to_string())?;
+        let (tx, rx) = mpsc::channel();
+        let (task, abort_handle) = abortable(forward(stream, tx));
+        tokio::spawn(task);
+        Ok(Self {
+            reference: reference.trim().to_string(),
+            status: PullStatus::new(),
+            rx,
+            abort_handle,
+            error: None,
+        })
+    }
+
+    /// Applies the progress received since the last call and tells the outcome once the pull ended.
+    pub fn update(&mut self) -> Option<Result<(), String>> {
+        loop {
+            match self.rx.try_recv() {
+                Ok(Ok(progress)) => self.status.apply(progress),
+                Ok(Err(e)) => self.error = Some(e),
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => {
+                    return Some(match self.error.take() {
+                        Some(e) => Err(e),
+                        None => Ok(()),
+                    })
+                }
+            }
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame<impl Backend>, rect: Rect) {
+        let title = format!("Pulling {} (Esc: cancel)", self.reference);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled(title, Style::default().fg(Color::DarkGray)));
+        let inner = block.inner(rect);
+        frame.render_widget(block, rect);
+
+        let messages = self.status.messages.iter().rev().take(2).rev();
+        let rows = messages.len() + self.status.layers.len();
+        let constraints: Vec<_> = (0..rows.min(inner.height as usize))
+            .map(|_| Constraint::Length(1))
+            .chain(std::iter::once(Constraint::Min(0)))
+            .collect();
+        let areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(inner);
+
+        let mut areas = areas.into_iter();
+        for message in messages {
+            match areas.next() {
+                Some(area) if area.height > 0 => frame.render_widget(Paragraph::new(message.as_str()), area),
+                _ => return,
+            }
+        }
+        for layer in self.status.layers.iter() {
+            let area = match areas.next() {
+                Some(area) if area.height > 0 => area,
+                _ => return,
+            };
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(14), Constraint::Length(20), Constraint::Min(0)].as_ref())
+                .split(area);
+            frame.render_widget(Paragraph::new(layer.id.as_str()), columns[0]);
+            frame.render_widget(Paragraph::new(layer.status.as_str()), columns[1]);
+            let color = if layer.is_done() { Color::Green } else { Color::Cyan };
+            let gauge = Gauge::default()
+                .gauge_style(Style::default().fg(color).bg(Color::Black))
+                .ratio(layer.ratio())
+                .label(format!("{:.0}%", layer.ratio() * 100.0));
+            frame.render_widget(gauge, columns[2]);
+        }
+    }
+}
+
+impl Drop for PullDialog {
+    fn drop(&mut self) {
+        self.abort_handle.abort();
+    }
+}
+
+async fn forward(mut stream: PullStream, tx: mpsc::Sender<Result<PullProgress, String>>) {
+    while let Some(item) = stream.next().await {
+        if tx.send(item.map_err(|e| e.to_string())).is_err() {
+            break;
+        }
+    }
+}
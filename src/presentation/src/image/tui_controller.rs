@@ -1,46 +1,149 @@
+use crate::events::feed::EventFeed;
+use crate::image::pull::PullDialog;
 use crate::shared::{
     event::{Event, Events},
+    input::TextInput,
     layout,
+    loader::{Loader, Next},
     paragraph::SimpleParagraph,
+    sort::{SortKey, Sorts},
+    spinner,
+    status_bar::StatusBar,
     table::{StatefulTable, StatelessTable},
     tabs::TabsState,
 };
 use crate::{TuiOperationStatus, TuiResult};
-use domain::image::ImageSummary;
+use domain::error::ErrorKind;
+use domain::event::EventKind;
+use domain::image::{ImageRemoveReport, ImageSummary};
+use std::cell::Cell;
+use std::error::Error;
 
 use termion::event::Key;
 use tui::{backend::Backend, layout::Constraint, Terminal};
 use usecase::{
     inspect_image::{HistoryRecord, InspectImageDto, InspectImageUsecase},
     list_image::ListImageUsecase,
+    pull_image::PullImageUsecase,
+    refresh_cache::RefreshCacheUsecase,
+    remove_image::RemoveImageUsecase,
+    tag_image::TagImageUsecase,
 };
 
 pub struct ImageTuiController<'a> {
     list_usecase: &'a ListImageUsecase<'a>,
     inspect_usecase: &'a InspectImageUsecase<'a>,
+    pull_usecase: &'a PullImageUsecase<'a>,
+    remove_usecase: &'a RemoveImageUsecase<'a>,
+    tag_usecase: &'a TagImageUsecase<'a>,
+    refresh_usecase: &'a RefreshCacheUsecase<'a>,
+    event_feed: &'a EventFeed,
+    sorts: &'a Sorts,
+    /// The revision of image events the cache last forgot the changed images for.
+    forgotten: Cell<u64>,
+}
+
+const IMAGES_TITLE: &str = "Images (p: pull, d: remove, t: tag, u: untag, a: dangling, r: refresh)";
+
+/// What the view loads in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Slot {
+    Images,
+    Detail,
+    /// Starting a pull, until the daemon answers with its progress stream.
+    Pull,
+    /// Tagging, untagging or removing an image, one at a time.
+    Change,
+}
+
+enum Loaded {
+    Images(Result<Vec<ImageSummary>, String>),
+    /// The details of the image with the ID.
+    Detail(String, Result<Box<InspectImageDto>, String>),
+    /// The pull of the reference, started.
+    Pull(String, Result<PullDialog, String>),
+    /// The new tag, added.
+    Tagged(String, Result<(), String>),
+    /// The tag, removed. Errors keep their kind to tell when forcing would help.
+    Untagged(String, Result<(), Box<dyn Error + Send + Sync>>),
+    /// The name of the image, removed.
+    Removed(String, Result<ImageRemoveReport, Box<dyn Error + Send + Sync>>),
+}
+
+/// What the keys currently do.
+enum Mode {
+    Browse,
+    Pull(TextInput),
+    /// Naming the new tag of the image with the given ID.
+    Tag(String, TextInput),
+    ConfirmRemove {
+        id: String,
+        name: String,
+        force: bool,
+        no_prune: bool,
+    },
+    ConfirmUntag(String),
 }
 
 impl<'a> ImageTuiController<'a> {
-    pub fn new(list_usecase: &'a ListImageUsecase<'a>, inspect_usecase: &'a InspectImageUsecase) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        list_usecase: &'a ListImageUsecase<'a>,
+        inspect_usecase: &'a InspectImageUsecase,
+        pull_usecase: &'a PullImageUsecase<'a>,
+        remove_usecase: &'a RemoveImageUsecase<'a>,
+        tag_usecase: &'a TagImageUsecase<'a>,
+        refresh_usecase: &'a RefreshCacheUsecase<'a>,
+        event_feed: &'a EventFeed,
+        sorts: &'a Sorts,
+    ) -> Self {
         Self {
             list_usecase,
             inspect_usecase,
+            pull_usecase,
+            remove_usecase,
+            tag_usecase,
+            refresh_usecase,
+            event_feed,
+            sorts,
+            forgotten: Cell::new(0),
         }
     }
 
-    pub async fn draw(&self, terminal: &mut Terminal<impl Backend>, tab: &mut TabsState<'a>) -> TuiResult {
-        let mut images = self.list_usecase.list_image().await?;
+    /// Lists images with the details of the selected one. `p` asks for an image to pull and shows the
+    /// pull's progress until it is done. `d` removes the selected image after confirming with `y`, where
+    /// `f` and `k` toggle forcing and keeping untagged parents, `t` adds a tag and `u` removes the selected
+    /// one. An image has a row for each tag, or for each digest when it has none, and `a` toggles listing
+    /// dangling images, which have neither. Details are kept until events report the image changed, and
+    /// `r` fetches everything again.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        tab: &mut TabsState<'a>,
+        events: &Events,
+    ) -> TuiResult {
+        let mut status_bar = StatusBar::new();
+        let mut mode = Mode::Browse;
+        self.event_feed.update();
+        let mut revision = self.event_feed.revision(&EventKind::Image);
+        self.forget_changed(revision);
+        let mut dangling = false;
+        let mut loader = Loader::new();
+        self.load_images(&mut loader, dangling);
+        let mut images: Vec<ImageSummary> = vec![];
+        let mut rows: Vec<ImageRow> = vec![];
         // image list table
-        let items = images_to_table(&mut images);
-        let header = vec!["NAME", "SIZE", "CREATED"];
+        let header = vec!["NAME", "IMAGE ID", "SIZE", "CREATED"];
         let widths = vec![
-            Constraint::Percentage(65),
+            Constraint::Percentage(50),
+            Constraint::Percentage(15),
             Constraint::Percentage(10),
             Constraint::Percentage(25),
         ];
-        let mut image_table = StatefulTable::new(items, "Images", header, widths);
+        let mut image_table = StatefulTable::new(vec![], IMAGES_TITLE, header, widths);
+        image_table.set_sort(self.sorts.get("Image"));
         // image detail paragraph
-        let mut paragraph = SimpleParagraph::new("Detail", vec!["It shows container's details here"]);
+        let mut paragraph = SimpleParagraph::new("Detail", vec!["It shows image's details here"]);
         // image history table
         let header = vec!["IMAGE ID", "CREATED BY", "SIZE"];
         let widths = vec![
@@ -49,10 +152,31 @@ impl<'a> ImageTuiController<'a> {
             Constraint::Percentage(15),
         ];
         let mut history_table = StatelessTable::new(vec![], "History", header, widths);
-        let events = Events::new();
+        let mut pull: Option<PullDialog> = None;
+        let mut reload = false;
 
         // Input
         loop {
+            if let Some(dialog) = pull.as_mut() {
+                if let Some(outcome) = dialog.update() {
+                    match outcome {
+                        Ok(()) => status_bar.info(format!("Pulled {}", dialog.reference)),
+                        Err(e) => status_bar.error(format!("Failed to pull {}: {}", dialog.reference, e)),
+                    }
+                    pull = None;
+                    reload = true;
+                }
+            }
+            // Reload the list whenever images were e.g. pulled, tagged or removed.
+            self.event_feed.update();
+            if self.event_feed.revision(&EventKind::Image) != revision || reload {
+                revision = self.event_feed.revision(&EventKind::Image);
+                reload = false;
+                self.forget_changed(revision);
+                self.load_images(&mut loader, dangling);
+            }
+            image_table.title = spinner::title(IMAGES_TITLE, loader.is_loading(Slot::Images));
+            paragraph.title = spinner::title("Detail", loader.is_loading(Slot::Detail));
             terminal.draw(|f| {
                 // TODO: Change it when split assignments are included in Rust's standard functions.
                 let areas = layout::split_into_header_and_main(f);
@@ -60,10 +184,22 @@ impl<'a> ImageTuiController<'a> {
                 let main = areas.1;
                 tab.draw(f, header);
                 // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_off_bottom_rows(main, 1);
+                let main = areas.0;
+                let status = areas.1;
+                match &mode {
+                    Mode::Pull(input) | Mode::Tag(_, input) => input.render(f, status),
+                    _ => status_bar.render(f, status),
+                }
+                // TODO: Change it when split assignments are included in Rust's standard functions.
                 let areas = layout::split_into_horizontal_pains(main);
                 let left_pain = areas.0;
                 let right_pain = areas.1;
                 image_table.render(f, left_pain);
+                if let Some(dialog) = &pull {
+                    dialog.render(f, right_pain);
+                    return;
+                }
                 // TODO: Change it when split assignments are included in Rust's standard functions.
                 let areas = layout::split_into_vertical_pains(right_pain);
                 let detail_up = areas.0;
@@ -72,26 +208,277 @@ impl<'a> ImageTuiController<'a> {
                 history_table.render(f, detail_down);
             })?;
 
-            if let Event::Input(key) = events.next()? {
-                match key {
-                    Key::Char('q') => {
-                        break;
+            let key = match loader.next(events).await? {
+                Next::Event(Event::Input(key)) => key,
+                Next::Event(Event::Tick) => continue,
+                Next::Loaded(Loaded::Images(Ok(loaded))) => {
+                    let previous =
+                        selected_row(&image_table, &rows, &images).map(|(row, image)| (row.label(), image.id.clone()));
+                    images = loaded;
+                    rows = image_rows(&images);
+                    image_table.items = rows_to_table(&rows, &images);
+                    image_table.sort_keys = rows_to_sort_keys(&rows, &images);
+                    // Keep the selection on the same image wherever the list moved it, on the same tag if it
+                    // still has it.
+                    let selected = previous.as_ref().and_then(|(label, id)| {
+                        let same_image = |row: &ImageRow| images[row.image()].id == *id;
+                        rows.iter()
+                            .position(|row| same_image(row) && row.label() == *label)
+                            .or_else(|| rows.iter().position(same_image))
+                    });
+                    image_table.select(selected);
+                    match selected_row(&image_table, &rows, &images) {
+                        Some((_, image)) => self.load_detail(&mut loader, image.id.clone()),
+                        None if previous.is_some() => {
+                            loader.cancel(Slot::Detail);
+                            paragraph.texts = vec!["It shows image's details here".to_string()];
+                            history_table.items = vec![];
+                        }
+                        None => {}
                     }
-                    Key::Down => {
-                        image_table.next();
-                        // TODO: Change it when split assignments are included in Rust's standard functions.
-                        let selected = image_table.state.selected();
-                        let detail = gen_detail_text(selected, &images, self.inspect_usecase).await;
-                        paragraph.texts = detail.0;
-                        history_table.items = detail.1;
+                    continue;
+                }
+                Next::Loaded(Loaded::Images(Err(e))) => {
+                    status_bar.error(format!("Failed to list images: {}", e));
+                    continue;
+                }
+                Next::Loaded(Loaded::Pull(reference, dialog)) => {
+                    match dialog {
+                        Ok(dialog) => {
+                            status_bar.clear();
+                            pull = Some(dialog);
+                        }
+                        Err(e) => status_bar.error(format!("Failed to pull {}: {}", reference, e)),
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Tagged(reference, result)) => {
+                    match result {
+                        Ok(()) => {
+                            status_bar.info(format!("Tagged {}", reference));
+                            reload = true;
+                        }
+                        Err(e) => status_bar.error(format!("Failed to tag {}: {}", reference, e)),
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Untagged(reference, result)) => {
+                    match result {
+                        Ok(()) => {
+                            status_bar.info(format!("Removed tag {}", reference));
+                            reload = true;
+                        }
+                        Err(e) => status_bar.error(removal_error(&reference, e)),
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Removed(name, result)) => {
+                    match result {
+                        Ok(report) => {
+                            status_bar.info(format!(
+                                "Removed {}: {} untagged, {} deleted",
+                                name,
+                                report.untagged.len(),
+                                report.deleted.len()
+                            ));
+                            reload = true;
+                        }
+                        Err(e) => status_bar.error(removal_error(&name, e)),
                     }
-                    Key::Up => {
-                        image_table.previous();
-                        let selected = image_table.state.selected();
-                        let detail = gen_detail_text(selected, &images, self.inspect_usecase).await;
+                    continue;
+                }
+                Next::Loaded(Loaded::Detail(id, detail)) => {
+                    // The list may have been reloaded with another image at the selected row meanwhile.
+                    if let Some((_, image)) = selected_row(&image_table, &rows, &images).filter(|v| v.1.id == id) {
+                        let detail = gen_detail_text(detail, image);
                         paragraph.texts = detail.0;
                         history_table.items = detail.1;
                     }
+                    continue;
+                }
+            };
+            if image_table.is_searching() {
+                if image_table.handle_search_key(key) {
+                    match selected_row(&image_table, &rows, &images) {
+                        Some((_, image)) => self.load_detail(&mut loader, image.id.clone()),
+                        None => {
+                            loader.cancel(Slot::Detail);
+                            paragraph.texts = vec!["It shows image's details here".to_string()];
+                            history_table.items = vec![];
+                        }
+                    }
+                }
+                if !image_table.is_searching() {
+                    events.enable_exit_key();
+                }
+                continue;
+            }
+            mode = match mode {
+                Mode::Pull(mut input) => match key {
+                    Key::Char('\n') => {
+                        events.enable_exit_key();
+                        status_bar.info(format!("Pulling {}", input.value.trim()));
+                        self.start_pull(&mut loader, input.value);
+                        Mode::Browse
+                    }
+                    Key::Esc => {
+                        events.enable_exit_key();
+                        Mode::Browse
+                    }
+                    key => {
+                        input.handle_key(key);
+                        Mode::Pull(input)
+                    }
+                },
+                Mode::Tag(id, mut input) => match key {
+                    Key::Char('\n') => {
+                        events.enable_exit_key();
+                        status_bar.info(format!("Tagging {}", input.value.trim()));
+                        self.tag(&mut loader, id, input.value);
+                        Mode::Browse
+                    }
+                    Key::Esc => {
+                        events.enable_exit_key();
+                        Mode::Browse
+                    }
+                    key => {
+                        input.handle_key(key);
+                        Mode::Tag(id, input)
+                    }
+                },
+                Mode::ConfirmRemove {
+                    id,
+                    name,
+                    force,
+                    no_prune,
+                } => match key {
+                    Key::Char('y') => {
+                        status_bar.info(format!("Removing {}", name));
+                        self.remove(&mut loader, id, name, force, no_prune);
+                        Mode::Browse
+                    }
+                    Key::Char('f') | Key::Char('k') => {
+                        let force = force ^ (key == Key::Char('f'));
+                        let no_prune = no_prune ^ (key == Key::Char('k'));
+                        status_bar.info(remove_prompt(&name, force, no_prune));
+                        Mode::ConfirmRemove {
+                            id,
+                            name,
+                            force,
+                            no_prune,
+                        }
+                    }
+                    _ => {
+                        status_bar.clear();
+                        Mode::Browse
+                    }
+                },
+                Mode::ConfirmUntag(reference) => {
+                    if key == Key::Char('y') {
+                        status_bar.info(format!("Removing tag {}", reference));
+                        self.untag(&mut loader, reference);
+                    } else {
+                        status_bar.clear();
+                    }
+                    Mode::Browse
+                }
+                Mode::Browse => match key {
+                    Key::Char('q') => {
+                        break;
+                    }
+                    Key::Char('/') => {
+                        events.disable_exit_key();
+                        image_table.start_search();
+                        Mode::Browse
+                    }
+                    Key::Char('o') => {
+                        image_table.cycle_sort();
+                        self.sorts.set("Image", image_table.sort());
+                        Mode::Browse
+                    }
+                    Key::Esc if pull.is_some() || loader.is_loading(Slot::Pull) => {
+                        // Dropping the dialog closes the connection, which cancels the pull.
+                        pull = None;
+                        loader.cancel(Slot::Pull);
+                        status_bar.info("Cancelled the pull");
+                        Mode::Browse
+                    }
+                    Key::Char('p') if pull.is_none() && !loader.is_loading(Slot::Pull) => {
+                        events.disable_exit_key();
+                        Mode::Pull(TextInput::new("Image to pull, e.g. nginx:latest (Esc: cancel)"))
+                    }
+                    Key::Char('d') | Key::Char('t') | Key::Char('u') if loader.is_loading(Slot::Change) => {
+                        status_bar.info("Wait for the running change to finish");
+                        Mode::Browse
+                    }
+                    Key::Char('d') => match selected_row(&image_table, &rows, &images) {
+                        Some((row, image)) => {
+                            let name = row.label();
+                            status_bar.info(remove_prompt(&name, false, false));
+                            Mode::ConfirmRemove {
+                                id: image.id.clone(),
+                                name,
+                                force: false,
+                                no_prune: false,
+                            }
+                        }
+                        None => Mode::Browse,
+                    },
+                    Key::Char('t') => match selected_row(&image_table, &rows, &images) {
+                        Some((_, image)) => {
+                            events.disable_exit_key();
+                            let input = TextInput::new("New tag, e.g. myrepo/app:1.0 (Esc: cancel)");
+                            Mode::Tag(image.id.clone(), input)
+                        }
+                        None => Mode::Browse,
+                    },
+                    Key::Char('u') => match selected_row(&image_table, &rows, &images) {
+                        Some((ImageRow::Tag(_, tag), image)) => {
+                            if image.repo_tags.len() == 1 {
+                                status_bar.info(format!("Remove {}, the image's only tag, and the image? (y/n)", tag));
+                            } else {
+                                status_bar.info(format!("Remove tag {}? (y/n)", tag));
+                            }
+                            Mode::ConfirmUntag(tag.clone())
+                        }
+                        Some(_) => {
+                            status_bar.info("The selected row isn't a tag");
+                            Mode::Browse
+                        }
+                        None => Mode::Browse,
+                    },
+                    Key::Char('r') => {
+                        self.refresh_usecase.refresh();
+                        reload = true;
+                        status_bar.clear();
+                        Mode::Browse
+                    }
+                    Key::Char('a') => {
+                        dangling = !dangling;
+                        reload = true;
+                        status_bar.info(if dangling {
+                            "Listing dangling images"
+                        } else {
+                            "Hiding dangling images"
+                        });
+                        Mode::Browse
+                    }
+                    Key::Down | Key::Up => {
+                        if key == Key::Down {
+                            image_table.next();
+                        } else {
+                            image_table.previous();
+                        }
+                        match selected_row(&image_table, &rows, &images) {
+                            Some((_, image)) => self.load_detail(&mut loader, image.id.clone()),
+                            None => {
+                                loader.cancel(Slot::Detail);
+                                paragraph.texts = vec!["It shows image's details here".to_string()];
+                                history_table.items = vec![];
+                            }
+                        }
+                        Mode::Browse
+                    }
                     Key::Right => {
                         tab.next();
                         return Ok(TuiOperationStatus::Continue);
@@ -100,50 +487,203 @@ impl<'a> ImageTuiController<'a> {
                         tab.previous();
                         return Ok(TuiOperationStatus::Continue);
                     }
-                    _ => {}
-                }
+                    _ => Mode::Browse,
+                },
             };
         }
         Ok(TuiOperationStatus::Quit)
     }
+
+    /// Forgets the cached details of images which events reported changed, including those while another
+    /// tab was shown.
+    fn forget_changed(&self, revision: u64) {
+        let changed = revision.saturating_sub(self.forgotten.replace(revision)) as usize;
+        let events = self.event_feed.recent(Some(&EventKind::Image));
+        if changed > events.len() {
+            // Older events were dropped from the history.
+            self.refresh_usecase.refresh();
+        } else {
+            self.refresh_usecase.forget(&events[..changed]);
+        }
+    }
+
+    fn load_images(&self, loader: &mut Loader<'a, Slot, Loaded>, dangling: bool) {
+        let usecase = self.list_usecase;
+        loader.load(Slot::Images, async move {
+            Loaded::Images(usecase.list_image(dangling).await.map_err(|e| e.to_string()))
+        });
+    }
+
+    fn start_pull(&self, loader: &mut Loader<'a, Slot, Loaded>, reference: String) {
+        let usecase = self.pull_usecase;
+        loader.load(Slot::Pull, async move {
+            let dialog = PullDialog::start(usecase, &reference).await;
+            Loaded::Pull(reference.trim().to_string(), dialog)
+        });
+    }
+
+    fn tag(&self, loader: &mut Loader<'a, Slot, Loaded>, id: String, reference: String) {
+        let usecase = self.tag_usecase;
+        loader.load(Slot::Change, async move {
+            let result = usecase.tag(id, &reference).await.map_err(|e| e.to_string());
+            Loaded::Tagged(reference.trim().to_string(), result)
+        });
+    }
+
+    fn untag(&self, loader: &mut Loader<'a, Slot, Loaded>, reference: String) {
+        let usecase = self.tag_usecase;
+        loader.load(Slot::Change, async move {
+            let result = usecase.untag(&reference).await;
+            Loaded::Untagged(reference, result)
+        });
+    }
+
+    fn remove(&self, loader: &mut Loader<'a, Slot, Loaded>, id: String, name: String, force: bool, no_prune: bool) {
+        let usecase = self.remove_usecase;
+        loader.load(Slot::Change, async move {
+            let result = usecase.remove_image(id, force, no_prune).await;
+            Loaded::Removed(name, result)
+        });
+    }
+
+    fn load_detail(&self, loader: &mut Loader<'a, Slot, Loaded>, id: String) {
+        let usecase = self.inspect_usecase;
+        loader.load(Slot::Detail, async move {
+            let detail = usecase
+                .inspect_image(&id)
+                .await
+                .map(Box::new)
+                .map_err(|e| e.to_string());
+            Loaded::Detail(id, detail)
+        });
+    }
+}
+
+fn remove_prompt(name: &str, force: bool, no_prune: bool) -> String {
+    let on_off = |v: bool| if v { "on" } else { "off" };
+    format!(
+        "Remove image {}? (y: remove, f: force [{}], k: keep untagged parents [{}], other: cancel)",
+        name,
+        on_off(force),
+        on_off(no_prune)
+    )
+}
+
+/// The daemon refuses with a conflict while containers use the image, which forcing overrides.
+fn removal_error(name: &str, error: Box<dyn Error + Send + Sync>) -> String {
+    match ErrorKind::of(error.as_ref()) {
+        ErrorKind::Conflict => format!("Failed to remove {}: {} (press d, f, then y to force)", name, error),
+        _ => format!("Failed to remove {}: {}", name, error),
+    }
+}
+
+/// A row of the image table, with the index of its image.
+enum ImageRow {
+    Tag(usize, String),
+    /// Only for images without tags.
+    Digest(usize, String),
+    Dangling(usize),
+}
+
+impl ImageRow {
+    fn image(&self) -> usize {
+        match self {
+            ImageRow::Tag(i, _) | ImageRow::Digest(i, _) | ImageRow::Dangling(i) => *i,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            ImageRow::Tag(_, tag) => tag.clone(),
+            ImageRow::Digest(_, digest) => digest.clone(),
+            ImageRow::Dangling(_) => "<none>:<none>".to_string(),
+        }
+    }
 }
 
-fn images_to_table(images: &mut Vec<ImageSummary>) -> Vec<Vec<String>> {
-    let mut items: Vec<Vec<String>> = Vec::new();
-    for image in images.iter_mut() {
-        if &image.repo_tags[0] == "<none>:<none>" {
-            continue;
+fn image_rows(images: &[ImageSummary]) -> Vec<ImageRow> {
+    let mut rows = Vec::new();
+    for (i, image) in images.iter().enumerate() {
+        if !image.repo_tags.is_empty() {
+            rows.extend(image.repo_tags.iter().map(|t| ImageRow::Tag(i, t.clone())));
+        } else if !image.repo_digests.is_empty() {
+            rows.extend(image.repo_digests.iter().map(|d| ImageRow::Digest(i, d.clone())));
+        } else {
+            rows.push(ImageRow::Dangling(i));
         }
-        let mut row: Vec<String> = Vec::new();
-        row.push(std::mem::take(&mut image.repo_tags[0]));
-        let size = f64::from(image.size) / 1000000.0;
-        row.push(format!("{:.2}MB", size));
-        row.push(image.created.format("%Y-%m-%d").to_string());
-        items.push(row);
     }
-    items
+    rows
+}
+
+fn selected_row<'r>(
+    table: &StatefulTable,
+    rows: &'r [ImageRow],
+    images: &'r [ImageSummary],
+) -> Option<(&'r ImageRow, &'r ImageSummary)> {
+    let row = table.selected().and_then(|v| rows.get(v))?;
+    images.get(row.image()).map(|image| (row, image))
 }
 
-async fn gen_detail_text<'a>(
-    idx: Option<usize>,
-    images: &[ImageSummary],
-    inspect_image_usecase: &'a InspectImageUsecase<'a>,
+fn rows_to_table(rows: &[ImageRow], images: &[ImageSummary]) -> Vec<Vec<String>> {
+    rows.iter()
+        .map(|row| {
+            let image = &images[row.image()];
+            vec![
+                row.label(),
+                short_id(&image.id),
+                image.size.to_string(),
+                image.created.format("%Y-%m-%d").to_string(),
+            ]
+        })
+        .collect()
+}
+
+fn rows_to_sort_keys(rows: &[ImageRow], images: &[ImageSummary]) -> Vec<Vec<SortKey>> {
+    rows.iter()
+        .map(|row| {
+            let image = &images[row.image()];
+            vec![
+                SortKey::text(row.label()),
+                SortKey::text(short_id(&image.id)),
+                image.size.into(),
+                SortKey::Integer(image.created.timestamp()),
+            ]
+        })
+        .collect()
+}
+
+/// The first 12 hex digits, as the docker CLI shows IDs.
+fn short_id(id: &str) -> String {
+    let hex = id.split(':').next_back().unwrap_or(id);
+    hex.chars().take(12).collect()
+}
+
+fn gen_detail_text(
+    detail: Result<Box<InspectImageDto>, String>,
+    image: &ImageSummary,
 ) -> (Vec<String>, Vec<Vec<String>>) {
-    if let Some(v) = idx {
-        let image_id = &images[v].id;
-        let detail = inspect_image_usecase.inspect_image(image_id).await;
-        match detail {
-            Ok(v) => (format_detail_text(&v), format_history_text(v.history)),
-            Err(e) => (vec![format!("Failed to get container's details: {}", e)], vec![]),
-        }
-    } else {
-        (vec!["It shows container's details here".to_string()], vec![])
+    match detail {
+        Ok(v) => (format_detail_text(&v, image), format_history_text(v.history)),
+        Err(e) => (vec![format!("Failed to get image's details: {}", e)], vec![]),
     }
 }
 
-fn format_detail_text(detail: &InspectImageDto) -> Vec<String> {
+fn format_detail_text(detail: &InspectImageDto, summary: &ImageSummary) -> Vec<String> {
     let mut texts = Vec::new();
     texts.push(format!("id: {}", detail.id));
+    texts.push(format!("parent: {}", summary.parent_id.as_deref().unwrap_or("-")));
+    texts.push(format!(
+        "shared size: {}",
+        summary.shared_size.map_or("-".to_string(), |v| v.to_string())
+    ));
+    texts.push(format!(
+        "containers: {}",
+        summary.containers.map_or("-".to_string(), |v| v.to_string())
+    ));
+    texts.push("tags: ".to_string());
+    summary.repo_tags.iter().for_each(|v| texts.push(format!("- {}", v)));
+    texts.push("digests: ".to_string());
+    summary.repo_digests.iter().for_each(|v| texts.push(format!("- {}", v)));
     texts.push(format!("os/arch: {}/{}", detail.os, detail.architecture));
     texts.push(format!("entrypoint: {:?}", detail.entrypoint));
     texts.push(format!("cmd: {:?}", detail.cmd));
@@ -171,7 +711,7 @@ fn format_history_text(records: Vec<HistoryRecord>) -> Vec<Vec<String>> {
                     .split(':')
                     .collect::<Vec<&str>>()
                     .get(1)
-                    .map_or("none", |v| &v[..8])
+                    .map_or("none", |v| v.get(..8).unwrap_or(v))
                     .to_string(),
                 r.created_by,
                 r.size.to_string(),
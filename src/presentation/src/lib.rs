@@ -1,5 +1,13 @@
+pub mod container;
+pub mod context;
+pub mod events;
+pub mod exec;
 pub mod image;
+pub mod log;
+pub mod network;
 pub mod shared;
+pub mod system;
+pub mod volume;
 
 use std::error::Error;
 type TuiResult = Result<TuiOperationStatus, Box<dyn Error + Send + Sync>>;
@@ -8,4 +16,6 @@ type TuiResult = Result<TuiOperationStatus, Box<dyn Error + Send + Sync>>;
 pub enum TuiOperationStatus {
     Continue,
     Quit,
+    /// Reconnect to the daemon of the named docker context.
+    SwitchContext(String),
 }
@@ -0,0 +1,40 @@
+use domain::log::LogLine;
+use std::collections::VecDeque;
+
+/// Keeps the most recent `capacity` log lines so that following a chatty container
+/// for a long time doesn't grow memory without bound.
+pub struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Appends a line and returns `true` when the oldest one had to be dropped for it.
+    pub fn push(&mut self, line: LogLine) -> bool {
+        let evicted = self.lines.len() >= self.capacity;
+        if evicted {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+        evicted
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &LogLine> {
+        self.lines.iter()
+    }
+}
@@ -0,0 +1,206 @@
+use crate::log::buffer::LogBuffer;
+use crate::shared::event::{Event, Events};
+use crate::{TuiOperationStatus, TuiResult};
+use domain::log::{LogLine, LogOptions, LogSource, LogStream};
+
+use futures_util::future::abortable;
+use futures_util::stream::StreamExt;
+use std::sync::mpsc::{self, TryRecvError};
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Paragraph},
+    Terminal,
+};
+use usecase::read_logs::ReadLogsUsecase;
+
+const LOG_CAPACITY: usize = 10_000;
+
+pub struct LogTuiController<'a> {
+    read_usecase: &'a ReadLogsUsecase<'a>,
+}
+
+impl<'a> LogTuiController<'a> {
+    pub fn new(read_usecase: &'a ReadLogsUsecase<'a>) -> Self {
+        Self { read_usecase }
+    }
+
+    /// Shows the logs of the container full-screen until `Esc` goes back to the caller.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        events: &Events,
+        id: &str,
+        name: &str,
+    ) -> TuiResult {
+        let mut buffer = LogBuffer::new(LOG_CAPACITY);
+        let mut view = LogView::new();
+        let mut notice = None;
+
+        let (tx, rx) = mpsc::channel();
+        let abort_handle = match self.read_usecase.read_logs(id, LogOptions::default()).await {
+            Ok(stream) => {
+                let (task, abort_handle) = abortable(forward(stream, tx));
+                tokio::spawn(task);
+                Some(abort_handle)
+            }
+            Err(e) => {
+                notice = Some(format!("Failed to read container's logs: {}", e));
+                None
+            }
+        };
+
+        let status = loop {
+            loop {
+                match rx.try_recv() {
+                    Ok(Ok(line)) => {
+                        if buffer.push(line) {
+                            view.top = view.top.saturating_sub(1);
+                        }
+                    }
+                    Ok(Err(e)) => notice = Some(format!("Log stream failed: {}", e)),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => {
+                        if notice.is_none() && abort_handle.is_some() {
+                            notice = Some("-- end of logs --".to_string());
+                        }
+                        break;
+                    }
+                }
+            }
+
+            terminal.draw(|f| {
+                let area = f.size();
+                view.height = area.height.saturating_sub(2) as usize;
+                let start = view.first_visible(buffer.len());
+                let mut lines: Vec<Spans> = buffer
+                    .iter()
+                    .skip(start)
+                    .take(view.height)
+                    .map(|line| to_spans(line, view.show_timestamps))
+                    .collect();
+                if let Some(notice) = &notice {
+                    if lines.len() >= view.height {
+                        lines.remove(0);
+                    }
+                    lines.push(Spans::from(Span::styled(
+                        notice.clone(),
+                        Style::default().add_modifier(Modifier::ITALIC),
+                    )));
+                }
+                let title = format!(
+                    "Logs: {} [{}] (f: follow/pause, t: timestamps, Esc: back)",
+                    name,
+                    if view.follow { "following" } else { "paused" }
+                );
+                let block = Block::default()
+                    .borders(Borders::ALL)
+                    .title(Span::styled(title, Style::default().fg(Color::DarkGray)));
+                f.render_widget(Paragraph::new(lines).block(block), area);
+            })?;
+
+            // The runtime has a single thread, so the task forwarding the stream only runs while this
+            // awaits. New lines show by the next tick.
+            if let Event::Input(key) = events.next_async().await? {
+                match key {
+                    Key::Char('q') => break TuiOperationStatus::Quit,
+                    Key::Esc => break TuiOperationStatus::Continue,
+                    Key::Char('f') => view.toggle_follow(buffer.len()),
+                    Key::Char('t') => view.show_timestamps = !view.show_timestamps,
+                    Key::Up => view.scroll_up(1, buffer.len()),
+                    Key::Down => view.scroll_down(1, buffer.len()),
+                    Key::PageUp => view.scroll_up(view.height, buffer.len()),
+                    Key::PageDown => view.scroll_down(view.height, buffer.len()),
+                    Key::Char('g') | Key::Home => {
+                        view.follow = false;
+                        view.top = 0;
+                    }
+                    Key::Char('G') | Key::End => view.follow = true,
+                    _ => {}
+                }
+            }
+        };
+
+        // Dropping the stream closes the connection to the daemon.
+        if let Some(abort_handle) = abort_handle {
+            abort_handle.abort();
+        }
+        Ok(status)
+    }
+}
+
+async fn forward(mut stream: LogStream, tx: mpsc::Sender<Result<LogLine, String>>) {
+    while let Some(item) = stream.next().await {
+        if tx.send(item.map_err(|e| e.to_string())).is_err() {
+            break;
+        }
+    }
+}
+
+struct LogView {
+    follow: bool,
+    show_timestamps: bool,
+    /// Index of the first visible line while paused.
+    top: usize,
+    height: usize,
+}
+
+impl LogView {
+    fn new() -> Self {
+        Self {
+            follow: true,
+            show_timestamps: true,
+            top: 0,
+            height: 0,
+        }
+    }
+
+    fn bottom(&self, len: usize) -> usize {
+        len.saturating_sub(self.height)
+    }
+
+    fn first_visible(&self, len: usize) -> usize {
+        if self.follow {
+            self.bottom(len)
+        } else {
+            self.top.min(self.bottom(len))
+        }
+    }
+
+    fn toggle_follow(&mut self, len: usize) {
+        if self.follow {
+            self.top = self.bottom(len);
+        }
+        self.follow = !self.follow;
+    }
+
+    fn scroll_up(&mut self, lines: usize, len: usize) {
+        self.top = self.first_visible(len).saturating_sub(lines);
+        self.follow = false;
+    }
+
+    fn scroll_down(&mut self, lines: usize, len: usize) {
+        self.top = (self.first_visible(len) + lines).min(self.bottom(len));
+        self.follow = false;
+    }
+}
+
+fn to_spans(line: &LogLine, show_timestamps: bool) -> Spans<'_> {
+    let mut spans = Vec::new();
+    if show_timestamps {
+        if let Some(timestamp) = line.timestamp {
+            spans.push(Span::styled(
+                format!("{} ", timestamp.format("%Y-%m-%dT%H:%M:%S%.3f")),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+    }
+    let style = match line.source {
+        LogSource::Stderr => Style::default().fg(Color::Red),
+        LogSource::Stdout => Style::default(),
+    };
+    spans.push(Span::styled(line.message.as_str(), style));
+    Spans::from(spans)
+}
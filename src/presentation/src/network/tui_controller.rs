@@ -0,0 +1,619 @@
+use crate::events::feed::EventFeed;
+use crate::shared::{
+    event::{Event, Events},
+    input::TextInput,
+    layout,
+    loader::{Loader, Next},
+    paragraph::SimpleParagraph,
+    sort::{SortKey, Sorts},
+    spinner,
+    status_bar::StatusBar,
+    table::{StatefulTable, StatelessTable},
+    tabs::TabsState,
+};
+use crate::{TuiOperationStatus, TuiResult};
+use domain::container::ContainerSummary;
+use domain::event::EventKind;
+use domain::network::Network;
+
+use std::net::Ipv4Addr;
+use termion::event::Key;
+use tui::{backend::Backend, layout::Constraint, Terminal};
+use usecase::{
+    connect_network::ConnectNetworkUsecase,
+    create_network::CreateNetworkUsecase,
+    inspect_network::{InspectNetworkDto, InspectNetworkUsecase},
+    list_containers::ListContainerUsecase,
+    list_networks::ListNetworksUsecase,
+    prune_networks::PruneNetworksUsecase,
+    remove_network::RemoveNetworkUsecase,
+};
+
+pub struct NetworkTuiController<'a> {
+    list_usecase: &'a ListNetworksUsecase<'a>,
+    inspect_usecase: &'a InspectNetworkUsecase<'a>,
+    create_usecase: &'a CreateNetworkUsecase<'a>,
+    remove_usecase: &'a RemoveNetworkUsecase<'a>,
+    prune_usecase: &'a PruneNetworksUsecase<'a>,
+    connect_usecase: &'a ConnectNetworkUsecase<'a>,
+    list_container_usecase: &'a ListContainerUsecase<'a>,
+    event_feed: &'a EventFeed,
+    sorts: &'a Sorts,
+}
+
+const NETWORKS_TITLE: &str = "Networks (a: attach, x: detach, c: create, d: remove, p: prune)";
+
+/// What the view loads in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Slot {
+    Networks,
+    Detail,
+    /// Listing the containers to attach.
+    Candidates,
+    /// Creating, removing, pruning or attaching, one at a time.
+    Change,
+}
+
+enum Loaded {
+    Networks(Result<Vec<Network>, String>),
+    /// The details of the network with the ID.
+    Detail(String, Result<Box<InspectNetworkDto>, String>),
+    /// The containers, to attach to the network with the ID.
+    Candidates(String, Result<Vec<ContainerSummary>, String>),
+    /// What a change did, or why it failed, for the status bar. Events reload the list.
+    Changed(Result<String, String>),
+}
+
+/// What the keys currently do.
+enum Mode {
+    Browse,
+    Create(TextInput),
+    ConfirmRemove(String, String),
+    ConfirmPrune,
+    /// Choosing the container to connect or, with `connect` false, to disconnect. Each candidate is
+    /// an ID and a name.
+    Pick {
+        connect: bool,
+        candidates: Vec<(String, String)>,
+        table: Box<StatefulTable>,
+    },
+}
+
+impl<'a> NetworkTuiController<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        list_usecase: &'a ListNetworksUsecase<'a>,
+        inspect_usecase: &'a InspectNetworkUsecase<'a>,
+        create_usecase: &'a CreateNetworkUsecase<'a>,
+        remove_usecase: &'a RemoveNetworkUsecase<'a>,
+        prune_usecase: &'a PruneNetworksUsecase<'a>,
+        connect_usecase: &'a ConnectNetworkUsecase<'a>,
+        list_container_usecase: &'a ListContainerUsecase<'a>,
+        event_feed: &'a EventFeed,
+        sorts: &'a Sorts,
+    ) -> Self {
+        Self {
+            list_usecase,
+            inspect_usecase,
+            create_usecase,
+            remove_usecase,
+            prune_usecase,
+            connect_usecase,
+            list_container_usecase,
+            event_feed,
+            sorts,
+        }
+    }
+
+    /// Lists networks with the addressing and the containers of the selected one. `a` attaches a container
+    /// and `x` detaches one, both picked from a list; `c` creates a network, `d` removes the selected one
+    /// and `p` prunes the unused ones, the last two after confirming with `y`.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        tab: &mut TabsState<'a>,
+        events: &Events,
+    ) -> TuiResult {
+        let mut status_bar = StatusBar::new();
+        let mut mode = Mode::Browse;
+        self.event_feed.update();
+        let mut revision = self.event_feed.revision(&EventKind::Network);
+        let mut loader = Loader::new();
+        self.load_networks(&mut loader);
+        let mut networks: Vec<Network> = vec![];
+        // The details of the selected network once loaded.
+        let mut detail: Option<Box<InspectNetworkDto>> = None;
+        // network list table
+        let header = vec!["NAME", "DRIVER", "SCOPE", "SUBNET", "GATEWAY"];
+        let widths = vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(12),
+            Constraint::Percentage(10),
+            Constraint::Percentage(26),
+            Constraint::Percentage(22),
+        ];
+        let mut network_table = StatefulTable::new(vec![], NETWORKS_TITLE, header, widths);
+        network_table.set_sort(self.sorts.get("Network"));
+        // network detail paragraph
+        let mut paragraph = SimpleParagraph::new("Detail", vec!["It shows network's details here"]);
+        // attached containers table
+        let header = vec!["CONTAINER", "IPV4 ADDRESS", "IPV6 ADDRESS", "MAC ADDRESS"];
+        let widths = vec![
+            Constraint::Percentage(25),
+            Constraint::Percentage(25),
+            Constraint::Percentage(30),
+            Constraint::Percentage(20),
+        ];
+        let mut container_table = StatelessTable::new(vec![], "Containers", header, widths);
+
+        loop {
+            // Reload whenever networks were created or removed, or containers connected or disconnected.
+            self.event_feed.update();
+            let current = self.event_feed.revision(&EventKind::Network);
+            if current != revision {
+                revision = current;
+                self.load_networks(&mut loader);
+            }
+            network_table.title = spinner::title(NETWORKS_TITLE, loader.is_loading(Slot::Networks));
+            paragraph.title = spinner::title("Detail", loader.is_loading(Slot::Detail));
+
+            terminal.draw(|f| {
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_header_and_main(f);
+                let header = areas.0;
+                let main = areas.1;
+                tab.draw(f, header);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_off_bottom_rows(main, 1);
+                let main = areas.0;
+                let status = areas.1;
+                match &mode {
+                    Mode::Create(input) => input.render(f, status),
+                    _ => status_bar.render(f, status),
+                }
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_horizontal_pains(main);
+                let left_pain = areas.0;
+                let right_pain = areas.1;
+                network_table.render(f, left_pain);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_vertical_pains(right_pain);
+                let detail_up = areas.0;
+                let detail_down = areas.1;
+                paragraph.render(f, detail_up);
+                match &mut mode {
+                    Mode::Pick { table, .. } => table.render(f, detail_down),
+                    _ => container_table.render(f, detail_down),
+                }
+            })?;
+
+            let key = match loader.next(events).await? {
+                Next::Event(Event::Input(key)) => key,
+                Next::Event(Event::Tick) => continue,
+                Next::Loaded(Loaded::Networks(Ok(loaded))) => {
+                    let previous = network_table
+                        .selected()
+                        .and_then(|v| networks.get(v))
+                        .map(|n| n.id.clone());
+                    networks = loaded;
+                    network_table.items = networks_to_table(&networks);
+                    network_table.sort_keys = networks_to_sort_keys(&networks);
+                    let selected = previous
+                        .as_ref()
+                        .and_then(|id| networks.iter().position(|n| n.id == *id));
+                    network_table.select(selected);
+                    if selected.is_some() || previous.is_some() {
+                        detail = None;
+                        self.inspect(
+                            &mut loader,
+                            selected.and_then(|v| networks.get(v)),
+                            &mut paragraph,
+                            &mut container_table,
+                        );
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Networks(Err(e))) => {
+                    status_bar.error(format!("Failed to list networks: {}", e));
+                    continue;
+                }
+                Next::Loaded(Loaded::Detail(id, loaded)) => {
+                    // The list may have been reloaded with another network at the selected row meanwhile.
+                    let selected = network_table.selected().and_then(|v| networks.get(v));
+                    if matches!(selected, Some(n) if n.id == id) {
+                        match loaded {
+                            Ok(loaded) => {
+                                paragraph.texts = format_detail_text(&loaded);
+                                container_table.items = format_container_text(&loaded);
+                                detail = Some(loaded);
+                            }
+                            Err(e) => {
+                                paragraph.texts = vec![];
+                                container_table.items = vec![];
+                                status_bar.error(format!("Failed to get network's details: {}", e));
+                            }
+                        }
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Candidates(id, containers)) => {
+                    // Only while still browsing the network the containers were listed for.
+                    let attached = detail.as_ref().filter(|d| d.id == id).map(|d| &d.containers);
+                    if let (Mode::Browse, Some(attached)) = (&mode, attached) {
+                        match containers {
+                            Ok(containers) => {
+                                let candidates: Vec<_> = containers
+                                    .iter()
+                                    .filter(|c| attached.iter().all(|a| a.id != c.id))
+                                    .map(|c| (c.id.clone(), c.name().to_string()))
+                                    .collect();
+                                status_bar.info("Pick the container to attach (Enter: attach, Esc: cancel)");
+                                mode = pick(true, candidates);
+                            }
+                            Err(e) => status_bar.error(format!("Failed to list containers: {}", e)),
+                        }
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Changed(Ok(message))) => {
+                    status_bar.info(message);
+                    continue;
+                }
+                Next::Loaded(Loaded::Changed(Err(message))) => {
+                    status_bar.error(message);
+                    continue;
+                }
+            };
+            if network_table.is_searching() {
+                if network_table.handle_search_key(key) {
+                    detail = None;
+                    let selected = network_table.selected().and_then(|v| networks.get(v));
+                    self.inspect(&mut loader, selected, &mut paragraph, &mut container_table);
+                }
+                if !network_table.is_searching() {
+                    events.enable_exit_key();
+                }
+                continue;
+            }
+            let selected = network_table.selected().and_then(|v| networks.get(v));
+            mode = match mode {
+                Mode::Create(mut input) => match key {
+                    Key::Char('\n') => {
+                        events.enable_exit_key();
+                        let name = input.value;
+                        status_bar.info(format!("Creating network {}", name.trim()));
+                        let usecase = self.create_usecase;
+                        loader.load(Slot::Change, async move {
+                            Loaded::Changed(match usecase.create_network(name.as_str()).await {
+                                Ok(_) => Ok(format!("Created network {}", name.trim())),
+                                Err(e) => Err(format!("Failed to create network: {}", e)),
+                            })
+                        });
+                        Mode::Browse
+                    }
+                    Key::Esc => {
+                        events.enable_exit_key();
+                        status_bar.clear();
+                        Mode::Browse
+                    }
+                    key => {
+                        input.handle_key(key);
+                        Mode::Create(input)
+                    }
+                },
+                Mode::ConfirmRemove(id, name) => {
+                    if key == Key::Char('y') {
+                        status_bar.info(format!("Removing network {}", name));
+                        let usecase = self.remove_usecase;
+                        loader.load(Slot::Change, async move {
+                            Loaded::Changed(match usecase.remove_network(id).await {
+                                Ok(()) => Ok(format!("Removed network {}", name)),
+                                Err(e) => Err(format!("Failed to remove network {}: {}", name, e)),
+                            })
+                        });
+                    } else {
+                        status_bar.clear();
+                    }
+                    Mode::Browse
+                }
+                Mode::ConfirmPrune => {
+                    if key == Key::Char('y') {
+                        status_bar.info("Pruning networks");
+                        let usecase = self.prune_usecase;
+                        loader.load(Slot::Change, async move {
+                            Loaded::Changed(match usecase.prune_networks().await {
+                                Ok(report) => Ok(format!("Removed {} unused networks", report.networks_deleted.len())),
+                                Err(e) => Err(format!("Failed to prune networks: {}", e)),
+                            })
+                        });
+                    } else {
+                        status_bar.clear();
+                    }
+                    Mode::Browse
+                }
+                Mode::Pick {
+                    connect,
+                    candidates,
+                    mut table,
+                } => match (key, selected) {
+                    (Key::Down, _) => {
+                        table.next();
+                        Mode::Pick {
+                            connect,
+                            candidates,
+                            table,
+                        }
+                    }
+                    (Key::Up, _) => {
+                        table.previous();
+                        Mode::Pick {
+                            connect,
+                            candidates,
+                            table,
+                        }
+                    }
+                    (Key::Char('\n'), Some(network)) => {
+                        if let Some((id, name)) = table.selected().and_then(|v| candidates.get(v)) {
+                            let usecase = self.connect_usecase;
+                            let (id, name) = (id.clone(), name.clone());
+                            let (network_id, network_name) = (network.id.clone(), network.name.clone());
+                            status_bar.info(format!("Updating {}", name));
+                            loader.load(Slot::Change, async move {
+                                let result = if connect {
+                                    usecase.connect(network_id.as_str(), id.as_str()).await
+                                } else {
+                                    usecase.disconnect(network_id.as_str(), id.as_str()).await
+                                };
+                                Loaded::Changed(match (result, connect) {
+                                    (Ok(()), true) => Ok(format!("Attached {} to {}", name, network_name)),
+                                    (Ok(()), false) => Ok(format!("Detached {} from {}", name, network_name)),
+                                    (Err(e), _) => Err(format!("Failed to update {}: {}", name, e)),
+                                })
+                            });
+                        }
+                        Mode::Browse
+                    }
+                    _ => {
+                        status_bar.clear();
+                        Mode::Browse
+                    }
+                },
+                Mode::Browse => match key {
+                    Key::Char('q') => {
+                        break;
+                    }
+                    Key::Char('/') => {
+                        events.disable_exit_key();
+                        network_table.start_search();
+                        Mode::Browse
+                    }
+                    Key::Char('o') => {
+                        network_table.cycle_sort();
+                        self.sorts.set("Network", network_table.sort());
+                        Mode::Browse
+                    }
+                    Key::Down | Key::Up => {
+                        if key == Key::Down {
+                            network_table.next();
+                        } else {
+                            network_table.previous();
+                        }
+                        detail = None;
+                        let selected = network_table.selected().and_then(|v| networks.get(v));
+                        self.inspect(&mut loader, selected, &mut paragraph, &mut container_table);
+                        Mode::Browse
+                    }
+                    Key::Char('a') | Key::Char('x') | Key::Char('c') | Key::Char('d') | Key::Char('p')
+                        if loader.is_loading(Slot::Change) =>
+                    {
+                        status_bar.info("Wait for the running change to finish");
+                        Mode::Browse
+                    }
+                    Key::Char('a') => {
+                        if let Some(detail) = &detail {
+                            status_bar.info("Listing the containers to attach");
+                            let usecase = self.list_container_usecase;
+                            let id = detail.id.clone();
+                            loader.load(Slot::Candidates, async move {
+                                Loaded::Candidates(id, usecase.list_container().await.map_err(|e| e.to_string()))
+                            });
+                        }
+                        Mode::Browse
+                    }
+                    Key::Char('x') => match &detail {
+                        Some(detail) => {
+                            let candidates = detail
+                                .containers
+                                .iter()
+                                .map(|c| (c.id.clone(), c.name.clone()))
+                                .collect();
+                            status_bar.info("Pick the container to detach (Enter: detach, Esc: cancel)");
+                            pick(false, candidates)
+                        }
+                        None => Mode::Browse,
+                    },
+                    Key::Char('c') => {
+                        events.disable_exit_key();
+                        Mode::Create(TextInput::new("New network name (Esc: cancel)"))
+                    }
+                    Key::Char('d') => match selected {
+                        Some(network) => {
+                            status_bar.info(format!("Remove network {}? (y/n)", network.name));
+                            Mode::ConfirmRemove(network.id.clone(), network.name.clone())
+                        }
+                        None => Mode::Browse,
+                    },
+                    Key::Char('p') => {
+                        status_bar.info("Remove every network no container is connected to? (y/n)");
+                        Mode::ConfirmPrune
+                    }
+                    Key::Right => {
+                        tab.next();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    Key::Left => {
+                        tab.previous();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    _ => Mode::Browse,
+                },
+            };
+        }
+        Ok(TuiOperationStatus::Quit)
+    }
+
+    fn load_networks(&self, loader: &mut Loader<'a, Slot, Loaded>) {
+        let usecase = self.list_usecase;
+        loader.load(Slot::Networks, async move {
+            Loaded::Networks(usecase.list_networks().await.map_err(|e| e.to_string()))
+        });
+    }
+
+    /// Loads the details of the selected network, or shows that none is selected.
+    fn inspect(
+        &self,
+        loader: &mut Loader<'a, Slot, Loaded>,
+        network: Option<&Network>,
+        paragraph: &mut SimpleParagraph,
+        container_table: &mut StatelessTable,
+    ) {
+        loader.cancel(Slot::Candidates);
+        let network = match network {
+            Some(network) => network,
+            None => {
+                loader.cancel(Slot::Detail);
+                paragraph.texts = vec!["It shows network's details here".to_string()];
+                container_table.items = vec![];
+                return;
+            }
+        };
+        let usecase = self.inspect_usecase;
+        let id = network.id.clone();
+        loader.load(Slot::Detail, async move {
+            let detail = usecase
+                .inspect_network(id.as_str())
+                .await
+                .map(Box::new)
+                .map_err(|e| e.to_string());
+            Loaded::Detail(id, detail)
+        });
+    }
+}
+
+fn pick(connect: bool, candidates: Vec<(String, String)>) -> Mode {
+    let title = if connect {
+        "Attach container"
+    } else {
+        "Detach container"
+    };
+    let items = candidates.iter().map(|(_, name)| vec![name.clone()]).collect();
+    let mut table = StatefulTable::new(items, title, vec!["CONTAINER"], vec![Constraint::Percentage(100)]);
+    table.next();
+    Mode::Pick {
+        connect,
+        candidates,
+        table: Box::new(table),
+    }
+}
+
+fn networks_to_table(networks: &[Network]) -> Vec<Vec<String>> {
+    networks
+        .iter()
+        .map(|n| {
+            let config = n.ipam.config.first();
+            vec![
+                n.name.clone(),
+                n.driver.clone(),
+                n.scope.clone(),
+                config.map_or(String::new(), |c| c.subnet.clone()),
+                config.map_or(String::new(), |c| c.gateway.clone()),
+            ]
+        })
+        .collect()
+}
+
+fn networks_to_sort_keys(networks: &[Network]) -> Vec<Vec<SortKey>> {
+    networks
+        .iter()
+        .map(|n| {
+            let config = n.ipam.config.first();
+            vec![
+                SortKey::text(&n.name),
+                SortKey::text(&n.driver),
+                SortKey::text(&n.scope),
+                config.map_or(SortKey::Missing, |c| address_sort_key(&c.subnet)),
+                config.map_or(SortKey::Missing, |c| address_sort_key(&c.gateway)),
+            ]
+        })
+        .collect()
+}
+
+/// Sorts IPv4 addresses and subnets by value, subnets of the same address by their prefix length. Other
+/// addresses are sorted by their text, after these.
+fn address_sort_key(address: &str) -> SortKey {
+    if address.is_empty() {
+        return SortKey::Missing;
+    }
+    let mut parts = address.splitn(2, '/');
+    let ip = parts.next().and_then(|v| v.parse::<Ipv4Addr>().ok());
+    let prefix = match parts.next() {
+        Some(v) => v.parse::<u8>().ok(),
+        None => Some(32),
+    };
+    match (ip, prefix) {
+        (Some(ip), Some(prefix)) => SortKey::Integer(i64::from(u32::from(ip)) << 8 | i64::from(prefix)),
+        _ => SortKey::text(address),
+    }
+}
+
+fn format_detail_text(detail: &InspectNetworkDto) -> Vec<String> {
+    let mut texts = Vec::new();
+    texts.push(format!("id: {}", detail.id));
+    texts.push(format!("name: {}", detail.name));
+    texts.push(format!("driver: {}", detail.driver));
+    texts.push(format!("scope: {}", detail.scope));
+    if let Some(created) = detail.created {
+        texts.push(format!("created: {}", created.format("%Y-%m-%d %H:%M:%S")));
+    }
+    texts.push(format!("internal: {}", detail.internal));
+    texts.push(format!("attachable: {}", detail.attachable));
+
+    texts.push(format!("ipam ({}): ", detail.ipam_driver));
+    for subnet in detail.subnets.iter() {
+        let mut text = format!("- subnet: {}", subnet.subnet);
+        if !subnet.gateway.is_empty() {
+            text.push_str(&format!(", gateway: {}", subnet.gateway));
+        }
+        if !subnet.ip_range.is_empty() {
+            text.push_str(&format!(", ip range: {}", subnet.ip_range));
+        }
+        texts.push(text);
+    }
+
+    texts.push("options: ".to_string());
+    for (k, v) in detail.options.iter() {
+        texts.push(format!("- {}: {}", k, v));
+    }
+
+    texts.push("labels: ".to_string());
+    for (k, v) in detail.labels.iter() {
+        texts.push(format!("- {}: {}", k, v));
+    }
+
+    texts
+}
+
+fn format_container_text(detail: &InspectNetworkDto) -> Vec<Vec<String>> {
+    detail
+        .containers
+        .iter()
+        .map(|c| {
+            vec![
+                c.name.clone(),
+                c.ipv4_address.clone(),
+                c.ipv6_address.clone(),
+                c.mac_address.clone(),
+            ]
+        })
+        .collect()
+}
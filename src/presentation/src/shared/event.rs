@@ -1,9 +1,12 @@
+use futures_util::future::{self, Future};
+use futures_util::task::AtomicWaker;
 use std::io;
 use std::sync::mpsc;
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc,
 };
+use std::task::Poll;
 use std::thread;
 use std::time::Duration;
 
@@ -23,6 +26,8 @@ pub struct Events {
     input_handle: thread::JoinHandle<()>,
     ignore_exit_key: Arc<AtomicBool>,
     tick_handle: thread::JoinHandle<()>,
+    /// Wakes the task waiting in `next_async` whenever an event was sent.
+    waker: Arc<AtomicWaker>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -48,29 +53,32 @@ impl Events {
     pub fn with_config(config: Config) -> Events {
         let (tx, rx) = mpsc::channel();
         let ignore_exit_key = Arc::new(AtomicBool::new(false));
+        let waker = Arc::new(AtomicWaker::new());
         let input_handle = {
             let tx = tx.clone();
             let ignore_exit_key = ignore_exit_key.clone();
+            let waker = waker.clone();
             thread::spawn(move || {
                 let stdin = io::stdin();
-                for evt in stdin.keys() {
-                    if let Ok(key) = evt {
-                        if let Err(err) = tx.send(Event::Input(key)) {
-                            eprintln!("{}", err);
-                            return;
-                        }
-                        if !ignore_exit_key.load(Ordering::Relaxed) && key == config.exit_key {
-                            return;
-                        }
+                for key in stdin.keys().flatten() {
+                    if let Err(err) = tx.send(Event::Input(key)) {
+                        eprintln!("{}", err);
+                        return;
+                    }
+                    waker.wake();
+                    if !ignore_exit_key.load(Ordering::Relaxed) && key == config.exit_key {
+                        return;
                     }
                 }
             })
         };
         let tick_handle = {
+            let waker = waker.clone();
             thread::spawn(move || loop {
                 if tx.send(Event::Tick).is_err() {
                     break;
                 }
+                waker.wake();
                 thread::sleep(config.tick_rate);
             })
         };
@@ -79,6 +87,7 @@ impl Events {
             ignore_exit_key,
             input_handle,
             tick_handle,
+            waker,
         }
     }
 
@@ -86,13 +95,26 @@ impl Events {
         self.rx.recv()
     }
 
-    #[allow(dead_code)]
-    pub fn disable_exit_key(&mut self) {
+    /// Waits for the next event without blocking the thread, so that other futures of the task, e.g.
+    /// requests to the daemon, make progress meanwhile.
+    pub fn next_async(&self) -> impl Future<Output = Result<Event<Key>, mpsc::RecvError>> + '_ {
+        future::poll_fn(move |cx| {
+            // Registering before looking avoids missing an event sent in between.
+            self.waker.register(cx.waker());
+            match self.rx.try_recv() {
+                Ok(event) => Poll::Ready(Ok(event)),
+                Err(mpsc::TryRecvError::Empty) => Poll::Pending,
+                Err(mpsc::TryRecvError::Disconnected) => Poll::Ready(Err(mpsc::RecvError)),
+            }
+        })
+    }
+
+    /// Lets the exit key through as normal input, e.g. while typing into a shell.
+    pub fn disable_exit_key(&self) {
         self.ignore_exit_key.store(true, Ordering::Relaxed);
     }
 
-    #[allow(dead_code)]
-    pub fn enable_exit_key(&mut self) {
+    pub fn enable_exit_key(&self) {
         self.ignore_exit_key.store(false, Ordering::Relaxed);
     }
 }
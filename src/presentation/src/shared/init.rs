@@ -1,8 +1,27 @@
+use crate::container::tui_controller::ContainerTuiController;
+use crate::context::tui_controller::ContextTuiController;
+use crate::events::{feed::EventFeed, tui_controller::EventTuiController};
+use crate::exec::tui_controller::ExecTuiController;
 use crate::image::tui_controller::ImageTuiController;
-use crate::shared::tabs::TabsState;
-use crate::TuiOperationStatus;
-use infrastructure::webapi::rest::client;
+use crate::log::tui_controller::LogTuiController;
+use crate::network::tui_controller::NetworkTuiController;
+use crate::shared::{event::Events, placeholder, sort::Sorts, tabs::TabsState};
+use crate::system::tui_controller::SystemTuiController;
+use crate::volume::tui_controller::VolumeTuiController;
+use crate::{TuiOperationStatus, TuiResult};
+use infrastructure::config::context_repository::FileContextRepository;
+use infrastructure::webapi::cache::image_repository::CachedImageRepository;
+use infrastructure::webapi::client::Client;
+use infrastructure::webapi::rest::client::{ConnectionConfig, Endpoint, TcpRestApi, UnixRestApi};
+use infrastructure::webapi::rest::container_repository::RestfulApiContainerRepository;
+use infrastructure::webapi::rest::event_repository::RestfulApiEventRepository;
+use infrastructure::webapi::rest::exec_repository::RestfulApiExecRepository;
 use infrastructure::webapi::rest::image_repository::RestfulApiImageRepository;
+use infrastructure::webapi::rest::log_repository::RestfulApiLogRepository;
+use infrastructure::webapi::rest::network_repository::RestfulApiNetworkRepository;
+use infrastructure::webapi::rest::stats_repository::RestfulApiStatsRepository;
+use infrastructure::webapi::rest::system_repository::RestfulApiSystemRepository;
+use infrastructure::webapi::rest::volume_repository::RestfulApiVolumeRepository;
 use std::error::Error;
 use std::io;
 use termion::{input::MouseTerminal, raw::IntoRawMode, screen::AlternateScreen};
@@ -10,27 +29,216 @@ use tui::{
     backend::{Backend, TermionBackend},
     Terminal,
 };
-use usecase::{inspect_image::InspectImageUsecase, list_image::ListImageUsecase};
+use usecase::{
+    connect_network::ConnectNetworkUsecase, create_network::CreateNetworkUsecase, create_volume::CreateVolumeUsecase,
+    disk_usage::DiskUsageUsecase, exec_container::ExecContainerUsecase, inspect_container::InspectContainerUsecase,
+    inspect_image::InspectImageUsecase, inspect_network::InspectNetworkUsecase, inspect_volume::InspectVolumeUsecase,
+    list_containers::ListContainerUsecase, list_contexts::ListContextsUsecase, list_image::ListImageUsecase,
+    list_networks::ListNetworksUsecase, list_volumes::ListVolumesUsecase, monitor_stats::MonitorStatsUsecase,
+    operate_container::OperateContainerUsecase, prune_networks::PruneNetworksUsecase, prune_system::PruneSystemUsecase,
+    prune_volumes::PruneVolumesUsecase, pull_image::PullImageUsecase, read_logs::ReadLogsUsecase,
+    refresh_cache::RefreshCacheUsecase, remove_image::RemoveImageUsecase, remove_network::RemoveNetworkUsecase,
+    remove_volume::RemoveVolumeUsecase, tag_image::TagImageUsecase, watch_events::WatchEventsUsecase,
+};
+
+/// Connects to the daemon given by the options, falling back to the docker CLI's contexts and environment
+/// variables, and reconnects whenever another context is picked in the Context tab.
+pub async fn draw_by_default(
+    endpoint: Option<&str>,
+    api_version: Option<&str>,
+    context: Option<&str>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let context_repository = FileContextRepository::from_env();
+    let list_contexts_usecase = ListContextsUsecase::new(&context_repository);
+    let mut config = ConnectionConfig::resolve(endpoint, api_version, context, &context_repository)?;
+    // The connection to go back to when switching to another context fails.
+    let mut previous: Option<ConnectionConfig> = None;
+    let mut notice = None;
+    let mut tab_index = 0;
+    let sorts = Sorts::new();
 
-pub async fn draw_by_default() -> Result<(), Box<dyn Error + Send + Sync>> {
     // Terminal initialization
     let mut terminal = terminal()?;
-    let mut tab = TabsState::new_menu();
+    let events = Events::new();
+
+    loop {
+        let context_controller = ContextTuiController::new(&list_contexts_usecase, &config.context, notice.take());
+        let status = connect(
+            &mut terminal,
+            &events,
+            &mut tab_index,
+            &sorts,
+            &config,
+            &context_controller,
+        )
+        .await;
+        match (status, previous.take()) {
+            (Ok(TuiOperationStatus::SwitchContext(name)), _) => {
+                match ConnectionConfig::resolve(None, api_version, Some(&name), &context_repository) {
+                    Ok(next) => previous = Some(std::mem::replace(&mut config, next)),
+                    Err(e) => notice = Some(format!("Failed to switch to context {}: {}", name, e)),
+                }
+            }
+            (Ok(_), _) => break,
+            (Err(e), Some(last)) => {
+                notice = Some(format!("Failed to use context {}: {}", config.context, e));
+                config = last;
+                tab_index = TabsState::new_menu()
+                    .titles
+                    .iter()
+                    .position(|t| *t == "Context")
+                    .unwrap_or(0);
+            }
+            (Err(e), None) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
 
+/// Builds the client for the connection and draws the tabs until the user quits or switches contexts.
+async fn connect(
+    terminal: &mut Terminal<impl Backend>,
+    events: &Events,
+    tab_index: &mut usize,
+    sorts: &Sorts,
+    config: &ConnectionConfig,
+    context_controller: &ContextTuiController<'_>,
+) -> TuiResult {
+    match &config.endpoint {
+        Endpoint::Unix(path) => {
+            let client = UnixRestApi::new(path, &config.api_version);
+            draw(terminal, events, tab_index, sorts, &client, context_controller).await
+        }
+        Endpoint::Tcp { tls, .. } => {
+            let authority = config.endpoint.authority().unwrap_or_default();
+            let client = match &config.tls {
+                Some(tls_config) => TcpRestApi::new_with_tls(&authority, tls_config, &config.api_version)?,
+                None => TcpRestApi::new(&authority, *tls, &config.api_version),
+            };
+            draw(terminal, events, tab_index, sorts, &client, context_controller).await
+        }
+    }
+}
+
+async fn draw<T>(
+    terminal: &mut Terminal<impl Backend>,
+    events: &Events,
+    tab_index: &mut usize,
+    sorts: &Sorts,
+    client: &T,
+    context_controller: &ContextTuiController<'_>,
+) -> TuiResult
+where
+    T: Client + Send + Sync + 'static,
+{
+    let mut tab = TabsState::new_menu();
+    tab.index = *tab_index;
+    // events
+    let event_repository = RestfulApiEventRepository::new(client);
+    let watch_events_usecase = WatchEventsUsecase::new(&event_repository);
+    let event_feed = EventFeed::start(&watch_events_usecase).await;
+    let event_controller = EventTuiController::new(&event_feed);
+    // log
+    let log_repository = RestfulApiLogRepository::new(client);
+    let read_logs_usecase = ReadLogsUsecase::new(&log_repository);
+    let log_controller = LogTuiController::new(&read_logs_usecase);
+    // exec
+    let exec_repository = RestfulApiExecRepository::new(client);
+    let exec_container_usecase = ExecContainerUsecase::new(&exec_repository);
+    let exec_controller = ExecTuiController::new(&exec_container_usecase);
+    // stats
+    let stats_repository = RestfulApiStatsRepository::new(client);
+    let monitor_stats_usecase = MonitorStatsUsecase::new(&stats_repository);
+    // container
+    let container_repository = RestfulApiContainerRepository::new(client);
+    let list_container_usecase = ListContainerUsecase::new(&container_repository);
+    let inspect_container_usecase = InspectContainerUsecase::new(&container_repository);
+    let operate_container_usecase = OperateContainerUsecase::new(&container_repository);
+    let container_controller = ContainerTuiController::new(
+        &list_container_usecase,
+        &inspect_container_usecase,
+        &operate_container_usecase,
+        &monitor_stats_usecase,
+        &log_controller,
+        &exec_controller,
+        &event_feed,
+        sorts,
+    );
     // image
-    let client = client::new_restapi_client("/var/run/docker.sock");
-    let image_repository = RestfulApiImageRepository::new(&client);
+    let image_repository = CachedImageRepository::new(RestfulApiImageRepository::new(client));
     let list_image_usecase = ListImageUsecase::new(&image_repository);
     let inspect_image_usecase = InspectImageUsecase::new(&image_repository);
-    let image_controller = ImageTuiController::new(&list_image_usecase, &inspect_image_usecase);
+    let pull_image_usecase = PullImageUsecase::new(&image_repository);
+    let remove_image_usecase = RemoveImageUsecase::new(&image_repository);
+    let tag_image_usecase = TagImageUsecase::new(&image_repository);
+    let refresh_image_cache_usecase = RefreshCacheUsecase::new(&image_repository);
+    let image_controller = ImageTuiController::new(
+        &list_image_usecase,
+        &inspect_image_usecase,
+        &pull_image_usecase,
+        &remove_image_usecase,
+        &tag_image_usecase,
+        &refresh_image_cache_usecase,
+        &event_feed,
+        sorts,
+    );
+    // volume
+    let volume_repository = RestfulApiVolumeRepository::new(client);
+    let list_volumes_usecase = ListVolumesUsecase::new(&volume_repository);
+    let inspect_volume_usecase = InspectVolumeUsecase::new(&volume_repository, &container_repository);
+    let create_volume_usecase = CreateVolumeUsecase::new(&volume_repository);
+    let remove_volume_usecase = RemoveVolumeUsecase::new(&volume_repository);
+    let prune_volumes_usecase = PruneVolumesUsecase::new(&volume_repository);
+    let volume_controller = VolumeTuiController::new(
+        &list_volumes_usecase,
+        &inspect_volume_usecase,
+        &create_volume_usecase,
+        &remove_volume_usecase,
+        &prune_volumes_usecase,
+        &event_feed,
+        sorts,
+    );
+    // network
+    let network_repository = RestfulApiNetworkRepository::new(client);
+    let list_networks_usecase = ListNetworksUsecase::new(&network_repository);
+    let inspect_network_usecase = InspectNetworkUsecase::new(&network_repository);
+    let create_network_usecase = CreateNetworkUsecase::new(&network_repository);
+    let remove_network_usecase = RemoveNetworkUsecase::new(&network_repository);
+    let prune_networks_usecase = PruneNetworksUsecase::new(&network_repository);
+    let connect_network_usecase = ConnectNetworkUsecase::new(&network_repository);
+    let network_controller = NetworkTuiController::new(
+        &list_networks_usecase,
+        &inspect_network_usecase,
+        &create_network_usecase,
+        &remove_network_usecase,
+        &prune_networks_usecase,
+        &connect_network_usecase,
+        &list_container_usecase,
+        &event_feed,
+        sorts,
+    );
+    // system
+    let system_repository = RestfulApiSystemRepository::new(client);
+    let disk_usage_usecase = DiskUsageUsecase::new(&system_repository);
+    let prune_system_usecase = PruneSystemUsecase::new(&system_repository);
+    let system_controller = SystemTuiController::new(&disk_usage_usecase, &prune_system_usecase);
     loop {
-        let status = image_controller.draw(&mut terminal, &mut tab).await?;
-        if status == TuiOperationStatus::Quit {
-            break;
+        let status = match tab.titles[tab.index] {
+            "Container" => container_controller.draw(terminal, &mut tab, events).await?,
+            "Image" => image_controller.draw(terminal, &mut tab, events).await?,
+            "Volume" => volume_controller.draw(terminal, &mut tab, events).await?,
+            "Network" => network_controller.draw(terminal, &mut tab, events).await?,
+            "System" => system_controller.draw(terminal, &mut tab, events).await?,
+            "Events" => event_controller.draw(terminal, &mut tab, events).await?,
+            "Context" => context_controller.draw(terminal, &mut tab, events).await?,
+            _ => placeholder::draw(terminal, &mut tab, events).await?,
+        };
+        *tab_index = tab.index;
+        if status != TuiOperationStatus::Continue {
+            return Ok(status);
         }
     }
-
-    Ok(())
 }
 
 fn terminal() -> Result<Terminal<impl Backend>, Box<dyn Error + Send + Sync>> {
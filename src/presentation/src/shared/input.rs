@@ -0,0 +1,52 @@
+use termion::event::Key;
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::{Span, Spans},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// One line of text typed by the user, e.g. the name of a volume to create. The caller handles
+/// `Enter` and `Esc` and should disable the exit key while it is shown so that `q` can be typed.
+pub struct TextInput {
+    pub label: String,
+    pub value: String,
+}
+
+impl TextInput {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: String::new(),
+        }
+    }
+
+    /// Edits the value with the key and tells whether the key was used.
+    pub fn handle_key(&mut self, key: Key) -> bool {
+        match key {
+            Key::Char('\n') | Key::Char('\t') => false,
+            Key::Char(c) => {
+                self.value.push(c);
+                true
+            }
+            Key::Backspace => {
+                self.value.pop();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame<impl Backend>, rect: Rect) {
+        let label = format!("{}: ", self.label);
+        let cursor = rect.x + (label.chars().count() + self.value.chars().count()) as u16;
+        let spans = Spans::from(vec![
+            Span::styled(label, Style::default().fg(Color::Yellow)),
+            Span::raw(self.value.as_str()),
+        ]);
+        frame.render_widget(Paragraph::new(spans), rect);
+        frame.set_cursor(cursor.min(rect.x + rect.width.saturating_sub(1)), rect.y);
+    }
+}
@@ -33,3 +33,23 @@ pub fn split_into_vertical_pains(rect: Rect) -> (Rect, Rect) {
     let down = areas[1];
     (up, down)
 }
+
+pub fn split_off_top_rows(rect: Rect, rows: u16) -> (Rect, Rect) {
+    let areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(rows), Constraint::Min(0)].as_ref())
+        .split(rect);
+    let top = areas[0];
+    let rest = areas[1];
+    (top, rest)
+}
+
+pub fn split_off_bottom_rows(rect: Rect, rows: u16) -> (Rect, Rect) {
+    let areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(rows)].as_ref())
+        .split(rect);
+    let rest = areas[0];
+    let bottom = areas[1];
+    (rest, bottom)
+}
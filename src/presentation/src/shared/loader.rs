@@ -0,0 +1,85 @@
+use crate::shared::event::{Event, Events};
+use futures_util::future::{abortable, select, AbortHandle, Aborted, Either, Future};
+use futures_util::stream::{FuturesUnordered, StreamExt};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::pin::Pin;
+use std::sync::mpsc;
+use termion::event::Key;
+
+type Fetch<'a, K, V> = Pin<Box<dyn Future<Output = Result<(K, V), Aborted>> + 'a>>;
+
+/// What the render loop handles next.
+pub enum Next<V> {
+    Event(Event<Key>),
+    Loaded(V),
+}
+
+/// Runs requests to the daemon concurrently with the render loop, which waits for input, ticks and
+/// their results alike with `next`. They run in the loop's own task as use cases borrow the connection.
+///
+/// Each request fills a slot, e.g. the list or the details of the selected row. A newer request for a
+/// slot cancels the one in flight, so a late response for a previously selected row never shows up.
+pub struct Loader<'a, K, V> {
+    pending: FuturesUnordered<Fetch<'a, K, V>>,
+    in_flight: HashMap<K, AbortHandle>,
+}
+
+impl<'a, K, V> Loader<'a, K, V>
+where
+    K: Eq + Hash + Copy + 'a,
+    V: 'a,
+{
+    pub fn new() -> Self {
+        Self {
+            pending: FuturesUnordered::new(),
+            in_flight: HashMap::new(),
+        }
+    }
+
+    pub fn load(&mut self, slot: K, fetch: impl Future<Output = V> + 'a) {
+        let (fetch, abort_handle) = abortable(async move { (slot, fetch.await) });
+        if let Some(previous) = self.in_flight.insert(slot, abort_handle) {
+            previous.abort();
+        }
+        self.pending.push(Box::pin(fetch));
+    }
+
+    /// Drops the request in flight for the slot, if any.
+    pub fn cancel(&mut self, slot: K) {
+        if let Some(abort_handle) = self.in_flight.remove(&slot) {
+            abort_handle.abort();
+        }
+    }
+
+    pub fn is_loading(&self, slot: K) -> bool {
+        self.in_flight.contains_key(&slot)
+    }
+
+    pub async fn next(&mut self, events: &Events) -> Result<Next<V>, mpsc::RecvError> {
+        loop {
+            if self.pending.is_empty() {
+                return events.next_async().await.map(Next::Event);
+            }
+            let fetched = match select(events.next_async(), self.pending.next()).await {
+                Either::Left((event, _)) => return event.map(Next::Event),
+                Either::Right((fetched, _)) => fetched,
+            };
+            // Cancelled requests end with `Aborted`.
+            if let Some(Ok((slot, value))) = fetched {
+                self.in_flight.remove(&slot);
+                return Ok(Next::Loaded(value));
+            }
+        }
+    }
+}
+
+impl<'a, K, V> Default for Loader<'a, K, V>
+where
+    K: Eq + Hash + Copy + 'a,
+    V: 'a,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
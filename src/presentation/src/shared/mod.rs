@@ -1,7 +1,14 @@
 pub mod event;
 pub mod init;
+pub mod input;
 pub mod layout;
+pub mod loader;
 pub mod paragraph;
+pub mod placeholder;
+pub mod search;
+pub mod sort;
 pub mod span;
+pub mod spinner;
+pub mod status_bar;
 pub mod table;
 pub mod tabs;
@@ -0,0 +1,45 @@
+use crate::shared::{
+    event::{Event, Events},
+    layout,
+    paragraph::SimpleParagraph,
+    tabs::TabsState,
+};
+use crate::{TuiOperationStatus, TuiResult};
+
+use termion::event::Key;
+use tui::{backend::Backend, Terminal};
+
+/// Draws the menu and a notice for tabs which don't have their own controller yet.
+pub async fn draw(terminal: &mut Terminal<impl Backend>, tab: &mut TabsState<'_>, events: &Events) -> TuiResult {
+    let title = tab.titles[tab.index];
+    let paragraph = SimpleParagraph::new(title, vec![format!("{} view is not implemented yet", title)]);
+
+    loop {
+        terminal.draw(|f| {
+            // TODO: Change it when split assignments are included in Rust's standard functions.
+            let areas = layout::split_into_header_and_main(f);
+            let header = areas.0;
+            let main = areas.1;
+            tab.draw(f, header);
+            paragraph.render(f, main);
+        })?;
+
+        if let Event::Input(key) = events.next_async().await? {
+            match key {
+                Key::Char('q') => {
+                    break;
+                }
+                Key::Right => {
+                    tab.next();
+                    return Ok(TuiOperationStatus::Continue);
+                }
+                Key::Left => {
+                    tab.previous();
+                    return Ok(TuiOperationStatus::Continue);
+                }
+                _ => {}
+            }
+        };
+    }
+    Ok(TuiOperationStatus::Quit)
+}
@@ -0,0 +1,247 @@
+use crate::shared::input::TextInput;
+use regex::Regex;
+use termion::event::Key;
+use tui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    widgets::Widget,
+};
+
+/// What a table is searched for, typed after `/`. The text matches anywhere in a row, ignoring case
+/// unless it has capitals; `Tab` switches to taking it as a regular expression.
+pub struct Search {
+    input: TextInput,
+    regex: bool,
+    typing: bool,
+    matcher: Option<Regex>,
+}
+
+impl Search {
+    pub fn new() -> Self {
+        Self {
+            input: TextInput::new("/"),
+            regex: false,
+            typing: true,
+            matcher: None,
+        }
+    }
+
+    /// Whether keys still go to the query, until `Enter` is pressed.
+    pub fn is_typing(&self) -> bool {
+        self.typing
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.input.value.is_empty()
+    }
+
+    /// Edits the query with the key. `Enter` finishes typing it.
+    pub fn handle_key(&mut self, key: Key) {
+        match key {
+            Key::Char('\n') => self.typing = false,
+            Key::Char('\t') => self.regex = !self.regex,
+            key => {
+                self.input.handle_key(key);
+            }
+        }
+        self.matcher = self.compile();
+    }
+
+    /// Whether any column of the row matches. Everything matches an empty or invalid query.
+    pub fn matches(&self, row: &[String]) -> bool {
+        match &self.matcher {
+            Some(matcher) => row.iter().any(|cell| matcher.is_match(cell)),
+            None => true,
+        }
+    }
+
+    /// The query as shown in the title, e.g. `/nginx_` while typing or `re/^k8s` for a regular expression.
+    pub fn label(&self) -> String {
+        let mut label = format!("{}/{}", if self.regex { "re" } else { "" }, self.input.value);
+        if self.typing {
+            label.push('_');
+        }
+        if self.matcher.is_none() && !self.is_empty() {
+            label.push_str(" invalid");
+        }
+        label
+    }
+
+    /// Highlights the matches in the rows of a table already drawn to the area, found in the text of
+    /// each cell. `header` is the header line as drawn, which tells where each column starts, `rows`
+    /// are the rows drawn under it and `indent` is the width of the highlight symbol before them.
+    pub fn highlight<'a>(&'a self, header: &'a [String], rows: Vec<&'a [String]>, indent: u16) -> Highlight<'a> {
+        Highlight {
+            matcher: self.matcher.as_ref(),
+            header,
+            rows,
+            indent,
+        }
+    }
+
+    fn compile(&self) -> Option<Regex> {
+        let query = self.input.value.as_str();
+        if query.is_empty() {
+            return None;
+        }
+        if self.regex {
+            return Regex::new(query).ok();
+        }
+        let case = if query.chars().any(char::is_uppercase) {
+            ""
+        } else {
+            "(?i)"
+        };
+        Regex::new(&format!("{}{}", case, regex::escape(query))).ok()
+    }
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Restyles the matched text of each cell of a drawn table. Tables of this tui version take rows of
+/// plain text, so the cells drawn are matched to their text by where the columns start.
+pub struct Highlight<'a> {
+    matcher: Option<&'a Regex>,
+    header: &'a [String],
+    rows: Vec<&'a [String]>,
+    indent: u16,
+}
+
+impl<'a> Highlight<'a> {
+    /// Where each column starts in the header line, unless its name was cut short.
+    fn column_starts(&self, area: Rect, buf: &Buffer) -> Vec<Option<u16>> {
+        let y = area.top();
+        let mut line = String::new();
+        let mut starts = Vec::new();
+        for x in area.left()..area.right() {
+            starts.push((line.len(), x));
+            line.push_str(&buf.get(x, y).symbol);
+        }
+        let mut cursor = 0;
+        self.header
+            .iter()
+            .map(|name| {
+                let found = line[cursor..].find(name.as_str())? + cursor;
+                cursor = found + name.len();
+                starts.iter().find(|(start, _)| *start == found).map(|(_, x)| *x)
+            })
+            .collect()
+    }
+}
+
+impl<'a> Widget for Highlight<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let matcher = match self.matcher {
+            Some(matcher) => matcher,
+            None => return,
+        };
+        let style = Style::default()
+            .fg(Color::Black)
+            .bg(Color::Yellow)
+            .add_modifier(Modifier::BOLD);
+        let columns = self.column_starts(area, buf);
+        // The rows start below the header and the gap under it.
+        let ys = area.top().saturating_add(2)..area.bottom();
+        for (y, row) in ys.zip(&self.rows) {
+            for (i, cell) in row.iter().enumerate() {
+                let start = match columns.get(i) {
+                    Some(Some(start)) if i == 0 => start + self.indent,
+                    Some(Some(start)) => *start,
+                    _ => continue,
+                };
+                let end = columns[i + 1..]
+                    .iter()
+                    .find_map(|start| *start)
+                    .unwrap_or_else(|| area.right());
+                let found: Vec<_> = matcher
+                    .find_iter(cell)
+                    .map(|m| m.range())
+                    .filter(|range| !range.is_empty())
+                    .collect();
+                // Where the symbol of each drawn cell starts in the text.
+                let mut offset = 0;
+                for x in start..end {
+                    if found.iter().any(|range| range.contains(&offset)) {
+                        buf.get_mut(x, y).set_style(style);
+                    }
+                    offset += buf.get(x, y).symbol.len();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Search;
+    use termion::event::Key;
+    use tui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
+
+    fn search(query: &str, regex: bool) -> Search {
+        let mut search = Search::new();
+        if regex {
+            search.handle_key(Key::Char('\t'));
+        }
+        query.chars().for_each(|c| search.handle_key(Key::Char(c)));
+        search
+    }
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn matching_text() {
+        let lower = search("ngin", false);
+        assert!(lower.matches(&row(&["web", "NGINX:latest"])));
+        assert!(!lower.matches(&row(&["redis"])));
+        // Capitals make it case sensitive, and it is taken literally.
+        assert!(!search("Ngin", false).matches(&row(&["nginx"])));
+        assert!(search("a.b", false).matches(&row(&["a.b"])));
+        assert!(!search("a.b", false).matches(&row(&["axb"])));
+    }
+
+    #[test]
+    fn matching_regular_expressions() {
+        let search = search("^k8s_.*_1$", true);
+        assert!(search.matches(&row(&["k8s_web_1"])));
+        assert!(!search.matches(&row(&["my_k8s_web_1"])));
+        assert_eq!(search.label(), "re/^k8s_.*_1$_");
+    }
+
+    #[test]
+    fn matching_everything_while_invalid() {
+        let mut search = search("(", true);
+        search.handle_key(Key::Char('\n'));
+        assert!(search.matches(&row(&["anything"])));
+        assert_eq!(search.label(), "re/( invalid");
+    }
+
+    #[test]
+    fn highlighting_matches_within_cells() {
+        let search = search("^web", true);
+        let header = row(&["NAME", "IMAGE"]);
+        let rows = [row(&["web", "web"]), row(&["my_web", "nginx"])];
+        let area = Rect::new(0, 0, 16, 4);
+        let mut buf = Buffer::empty(area);
+        buf.set_string(0, 0, "NAME     IMAGE", Default::default());
+        buf.set_string(0, 2, ">> web   web", Default::default());
+        buf.set_string(0, 3, "   my_web nginx", Default::default());
+        search
+            .highlight(&header, rows.iter().map(|v| v.as_slice()).collect(), 3)
+            .render(area, &mut buf);
+        let highlighted = |y| {
+            (0..area.width)
+                .filter(|x| buf.get(*x, y).bg == Color::Yellow)
+                .collect::<Vec<_>>()
+        };
+        // Anchored at the start of each cell, after the highlight symbol.
+        assert_eq!(highlighted(2), vec![3, 4, 5, 9, 10, 11]);
+        assert_eq!(highlighted(3), Vec::<u16>::new());
+    }
+}
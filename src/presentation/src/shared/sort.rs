@@ -0,0 +1,156 @@
+use domain::size::ByteSize;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+/// The column a table is sorted by, as its index, and the direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sort {
+    pub column: usize,
+    pub descending: bool,
+}
+
+impl Sort {
+    /// Each column ascending then descending, then unsorted again.
+    pub fn cycle(sort: Option<Sort>, columns: usize) -> Option<Sort> {
+        match sort {
+            None if columns > 0 => Some(Sort {
+                column: 0,
+                descending: false,
+            }),
+            Some(Sort {
+                column,
+                descending: false,
+            }) => Some(Sort {
+                column,
+                descending: true,
+            }),
+            Some(Sort { column, .. }) if column + 1 < columns => Some(Sort {
+                column: column + 1,
+                descending: false,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The arrow shown after the header of the column.
+    pub fn indicator(&self) -> &'static str {
+        if self.descending {
+            "▼"
+        } else {
+            "▲"
+        }
+    }
+}
+
+/// What a cell is sorted by, so that e.g. sizes and dates compare by value rather than by their text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortKey {
+    /// Compared ignoring case.
+    Text(String),
+    Integer(i64),
+    Float(f64),
+    /// No value, e.g. the CPU usage of a stopped container. Sorted last in both directions.
+    Missing,
+}
+
+impl SortKey {
+    pub fn text(text: impl AsRef<str>) -> Self {
+        SortKey::Text(text.as_ref().to_lowercase())
+    }
+
+    /// Orders numbers before text.
+    pub fn compare(&self, other: &SortKey) -> Ordering {
+        match (self, other) {
+            (SortKey::Text(a), SortKey::Text(b)) => a.cmp(b),
+            (SortKey::Integer(a), SortKey::Integer(b)) => a.cmp(b),
+            (SortKey::Float(a), SortKey::Float(b)) => a.total_cmp(b),
+            (SortKey::Integer(a), SortKey::Float(b)) => (*a as f64).total_cmp(b),
+            (SortKey::Float(a), SortKey::Integer(b)) => a.total_cmp(&(*b as f64)),
+            (SortKey::Text(_), _) => Ordering::Greater,
+            (_, SortKey::Text(_)) => Ordering::Less,
+            (SortKey::Missing, _) | (_, SortKey::Missing) => Ordering::Equal,
+        }
+    }
+
+    pub fn is_missing(&self) -> bool {
+        *self == SortKey::Missing
+    }
+}
+
+impl From<ByteSize> for SortKey {
+    fn from(size: ByteSize) -> Self {
+        SortKey::Integer(i64::try_from(size.as_u64()).unwrap_or(i64::MAX))
+    }
+}
+
+impl<T: Into<SortKey>> From<Option<T>> for SortKey {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(SortKey::Missing, Into::into)
+    }
+}
+
+/// The sort chosen in each view, kept for the session although views make their tables anew whenever
+/// they are shown and when another context is connected.
+#[derive(Default)]
+pub struct Sorts {
+    sorts: RefCell<HashMap<&'static str, Sort>>,
+}
+
+impl Sorts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, view: &str) -> Option<Sort> {
+        self.sorts.borrow().get(view).copied()
+    }
+
+    pub fn set(&self, view: &'static str, sort: Option<Sort>) {
+        let mut sorts = self.sorts.borrow_mut();
+        match sort {
+            Some(sort) => sorts.insert(view, sort),
+            None => sorts.remove(view),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sort, SortKey};
+    use domain::size::ByteSize;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn comparing_values_rather_than_text() {
+        let (small, large) = (ByteSize(9_000), ByteSize(10_000));
+        assert!(small.to_string() > large.to_string());
+        assert_eq!(SortKey::from(small).compare(&large.into()), Ordering::Less);
+        // Dates are keyed by their timestamps.
+        let (before, after) = (SortKey::Integer(999_999_999), SortKey::Integer(1_000_000_000));
+        assert_eq!(before.compare(&after), Ordering::Less);
+        assert_eq!(SortKey::Float(2.5).compare(&SortKey::Integer(10)), Ordering::Less);
+        assert_eq!(
+            SortKey::text("Nginx").compare(&SortKey::text("alpine")),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn cycling_through_every_column() {
+        let sort = |column, descending| Some(Sort { column, descending });
+        let mut cycled = vec![];
+        let mut current = Sort::cycle(None, 2);
+        while let Some(v) = current {
+            cycled.push(Some(v));
+            current = Sort::cycle(current, 2);
+        }
+        assert_eq!(
+            cycled,
+            vec![sort(0, false), sort(0, true), sort(1, false), sort(1, true)]
+        );
+        assert_eq!(Sort::cycle(sort(1, true), 2), None);
+        assert_eq!(Sort::cycle(None, 0), None);
+    }
+}
@@ -0,0 +1,16 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Appends a spinner to the title while something is loading. It turns with the clock, so it moves
+/// whenever the view is drawn again.
+pub fn title(title: &str, loading: bool) -> String {
+    if !loading {
+        return title.to_string();
+    }
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    format!("{} {}", title, FRAMES[(millis / 100) as usize % FRAMES.len()])
+}
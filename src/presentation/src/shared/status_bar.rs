@@ -0,0 +1,46 @@
+use tui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Color, Style},
+    text::Span,
+    widgets::Paragraph,
+    Frame,
+};
+
+/// One line at the bottom of a view telling the outcome of the last operation.
+pub struct StatusBar {
+    message: Option<(String, bool)>,
+}
+
+impl StatusBar {
+    pub fn new() -> Self {
+        Self { message: None }
+    }
+
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.message = Some((message.into(), false));
+    }
+
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.message = Some((message.into(), true));
+    }
+
+    pub fn clear(&mut self) {
+        self.message = None;
+    }
+
+    pub fn render(&self, frame: &mut Frame<impl Backend>, rect: Rect) {
+        let span = match &self.message {
+            Some((message, true)) => Span::styled(message.as_str(), Style::default().fg(Color::White).bg(Color::Red)),
+            Some((message, false)) => Span::styled(message.as_str(), Style::default().fg(Color::Green)),
+            None => Span::raw(""),
+        };
+        frame.render_widget(Paragraph::new(span), rect);
+    }
+}
+
+impl Default for StatusBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
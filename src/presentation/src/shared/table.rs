@@ -1,3 +1,8 @@
+use crate::shared::search::Search;
+use crate::shared::sort::{Sort, SortKey};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use termion::event::Key;
 use tui::widgets::TableState;
 use tui::{
     backend::Backend,
@@ -8,15 +13,27 @@ use tui::{
     Frame,
 };
 
+const HIGHLIGHT_SYMBOL: &str = ">> ";
+
+/// A table whose rows can be selected, searched and sorted. Selection is by index into `items`, which
+/// stays the index of the same item while a search hides other rows or the rows are sorted.
 pub struct StatefulTable {
-    pub state: TableState,
+    state: TableState,
+    /// The first row drawn, which the table scrolls the way the widget does to show the selected row.
+    offset: usize,
+    selected: Option<usize>,
     pub items: Vec<Vec<String>>,
+    /// What each row of `items` is sorted by, one key for each column. Cells without a key are sorted
+    /// by their text.
+    pub sort_keys: Vec<Vec<SortKey>>,
     pub title: String,
     pub header: Vec<String>,
     pub widths: Vec<Constraint>,
+    search: Option<Search>,
+    sort: Option<Sort>,
 }
 
-impl<'a> StatefulTable {
+impl StatefulTable {
     pub fn new(
         items: Vec<Vec<String>>,
         title: impl Into<String>,
@@ -25,17 +42,49 @@ impl<'a> StatefulTable {
     ) -> StatefulTable {
         StatefulTable {
             state: TableState::default(),
+            offset: 0,
+            selected: None,
             items,
+            sort_keys: vec![],
             title: title.into(),
             header: header.into_iter().map(|s| s.into()).collect(),
             widths,
+            search: None,
+            sort: None,
         }
     }
 
+    pub fn sort(&self) -> Option<Sort> {
+        self.sort
+    }
+
+    pub fn set_sort(&mut self, sort: Option<Sort>) {
+        self.sort = sort;
+    }
+
+    /// Sorts by the next column or direction, see `Sort::cycle`.
+    pub fn cycle_sort(&mut self) {
+        self.sort = Sort::cycle(self.sort, self.header.len());
+    }
+
+    /// The index in `items` of the selected row, unless it is out of range or hidden by the search.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+            .filter(|i| self.items.get(*i).map_or(false, |row| self.is_shown(row)))
+    }
+
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
     pub fn next(&mut self) {
-        let i = match self.state.selected() {
+        let shown = self.shown();
+        if shown.is_empty() {
+            return;
+        }
+        let i = match self.selected().and_then(|v| shown.iter().position(|i| *i == v)) {
             Some(i) => {
-                if i >= self.items.len() - 1 {
+                if i >= shown.len() - 1 {
                     0
                 } else {
                     i + 1
@@ -43,21 +92,58 @@ impl<'a> StatefulTable {
             }
             None => 0,
         };
-        self.state.select(Some(i));
+        self.selected = Some(shown[i]);
     }
 
     pub fn previous(&mut self) {
-        let i = match self.state.selected() {
+        let shown = self.shown();
+        if shown.is_empty() {
+            return;
+        }
+        let i = match self.selected().and_then(|v| shown.iter().position(|i| *i == v)) {
             Some(i) => {
                 if i == 0 {
-                    self.items.len() - 1
+                    shown.len() - 1
                 } else {
                     i - 1
                 }
             }
             None => 0,
         };
-        self.state.select(Some(i));
+        self.selected = Some(shown[i]);
+    }
+
+    /// Starts typing a search, which hides the rows not matching it as it is typed. The caller passes
+    /// the keys on with `handle_search_key` while `is_searching`, and should disable the exit key.
+    pub fn start_search(&mut self) {
+        self.search = Some(Search::new());
+    }
+
+    /// Whether a search is being typed.
+    pub fn is_searching(&self) -> bool {
+        self.search.as_ref().map_or(false, |v| v.is_typing())
+    }
+
+    /// Edits the search being typed. `Enter` keeps the rows found and `Esc` shows all of them again, as
+    /// does finishing an empty search. Moves the selection to the first row found if it was hidden, and
+    /// tells whether the selection changed.
+    pub fn handle_search_key(&mut self, key: Key) -> bool {
+        let before = self.selected();
+        match key {
+            Key::Esc => self.search = None,
+            key => {
+                if let Some(search) = self.search.as_mut() {
+                    search.handle_key(key);
+                    if !search.is_typing() && search.is_empty() {
+                        self.search = None;
+                    }
+                }
+            }
+        }
+        if self.selected().is_none() {
+            self.selected = self.shown().first().copied();
+        }
+        self.selected() != before
     }
 
     pub fn render(&mut self, frame: &mut Frame<impl Backend>, rect: Rect) {
@@ -65,20 +151,100 @@ impl<'a> StatefulTable {
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD);
         let normal_style = Style::default().fg(Color::DarkGray);
-        let rows = self
-            .items
+        let shown = self.shown();
+        let title = match &self.search {
+            Some(search) => format!(
+                "{} [{}: {} of {}]",
+                self.title,
+                search.label(),
+                shown.len(),
+                self.items.len()
+            ),
+            None => self.title.clone(),
+        };
+        let selected = self.selected().and_then(|v| shown.iter().position(|i| *i == v));
+        self.state.select(selected);
+        // Inside the borders, below the header and the gap under it.
+        let height = rect.height.saturating_sub(4) as usize;
+        self.offset = match selected {
+            _ if height == 0 => self.offset,
+            Some(i) if i + 1 >= self.offset + height => (i + 1).saturating_sub(height),
+            Some(i) if i < self.offset => i,
+            Some(_) => self.offset,
+            None => 0,
+        };
+        let header: Vec<String> = self
+            .header
             .iter()
-            .map(|i| Row::StyledData(i.iter(), normal_style));
-        let t = Table::new(self.header.iter(), rows)
+            .enumerate()
+            .map(|(i, name)| match self.sort {
+                Some(sort) if sort.column == i => format!("{} {}", name, sort.indicator()),
+                _ => name.clone(),
+            })
+            .collect();
+        let items = &self.items;
+        let rows = shown
+            .iter()
+            .map(|i| Row::StyledData(items[*i].iter(), normal_style));
+        let t = Table::new(header.iter(), rows)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(Spans::from(self.title.clone())),
+                    .title(Spans::from(title)),
             )
             .highlight_style(selected_style)
-            .highlight_symbol(">> ")
+            .highlight_symbol(HIGHLIGHT_SYMBOL)
             .widths(&self.widths);
         frame.render_stateful_widget(t, rect, &mut self.state);
+        if let Some(search) = &self.search {
+            let inner = Rect {
+                x: rect.x + 1,
+                y: rect.y + 1,
+                width: rect.width.saturating_sub(2),
+                height: rect.height.saturating_sub(2),
+            };
+            let rows = shown
+                .iter()
+                .skip(self.offset)
+                .take(height)
+                .map(|i| self.items[*i].as_slice())
+                .collect();
+            let highlight = search.highlight(&header, rows, HIGHLIGHT_SYMBOL.len() as u16);
+            frame.render_widget(highlight, inner);
+        }
+    }
+
+    fn is_shown(&self, row: &[String]) -> bool {
+        self.search.as_ref().map_or(true, |v| v.matches(row))
+    }
+
+    /// Indices of the rows the search leaves, in the order of the sort.
+    fn shown(&self) -> Vec<usize> {
+        let mut shown: Vec<usize> = (0..self.items.len())
+            .filter(|i| self.is_shown(&self.items[*i]))
+            .collect();
+        if let Some(sort) = self.sort {
+            shown.sort_by(|a, b| {
+                let a = self.sort_key(*a, sort.column);
+                let b = self.sort_key(*b, sort.column);
+                match (a.is_missing(), b.is_missing()) {
+                    (false, true) => Ordering::Less,
+                    (true, false) => Ordering::Greater,
+                    _ if sort.descending => b.compare(&a),
+                    _ => a.compare(&b),
+                }
+            });
+        }
+        shown
+    }
+
+    fn sort_key(&self, row: usize, column: usize) -> Cow<'_, SortKey> {
+        match self.sort_keys.get(row).and_then(|keys| keys.get(column)) {
+            Some(key) => Cow::Borrowed(key),
+            None => Cow::Owned(SortKey::text(
+                self.items[row].get(column).map_or("", |cell| cell.as_str()),
+            )),
+        }
     }
 }
 
@@ -121,3 +287,70 @@ impl StatelessTable {
         frame.render_widget(image_history, rect);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::StatefulTable;
+    use crate::shared::sort::{Sort, SortKey};
+    use termion::event::Key;
+    use tui::layout::Constraint;
+
+    fn table(names: &[&str]) -> StatefulTable {
+        let items = names.iter().map(|v| vec![v.to_string()]).collect();
+        StatefulTable::new(items, "", vec!["NAME"], vec![Constraint::Percentage(100)])
+    }
+
+    fn search(table: &mut StatefulTable, query: &str) {
+        table.start_search();
+        for c in query.chars().chain(Some('\n')) {
+            table.handle_search_key(Key::Char(c));
+        }
+    }
+
+    #[test]
+    fn moving_over_the_rows_found() {
+        let mut table = table(&["nginx", "redis", "nginx-proxy", "postgres"]);
+        search(&mut table, "nginx");
+        assert_eq!(table.selected(), Some(0));
+        table.next();
+        assert_eq!(table.selected(), Some(2));
+        table.next();
+        assert_eq!(table.selected(), Some(0));
+        table.previous();
+        assert_eq!(table.selected(), Some(2));
+    }
+
+    #[test]
+    fn selecting_items_rather_than_rows() {
+        let mut table = table(&["redis", "nginx", "postgres"]);
+        search(&mut table, "s");
+        assert_eq!(table.selected(), Some(0));
+        table.next();
+        assert_eq!(table.selected(), Some(2));
+        // A selection the search hides moves to the first row found.
+        search(&mut table, "redis");
+        assert_eq!(table.selected(), Some(0));
+        table.handle_search_key(Key::Esc);
+        table.select(Some(3));
+        assert_eq!(table.selected(), None);
+    }
+
+    #[test]
+    fn sorting_missing_values_last() {
+        let mut table = table(&["stopped", "busy", "idle"]);
+        table.sort_keys = vec![vec![SortKey::Missing], vec![SortKey::Float(80.0)], vec![SortKey::Float(0.5)]];
+        for (descending, order) in [(false, [2, 1, 0]), (true, [1, 2, 0])].iter() {
+            table.set_sort(Some(Sort {
+                column: 0,
+                descending: *descending,
+            }));
+            table.select(None);
+            let mut selected = vec![];
+            for _ in 0..3 {
+                table.next();
+                selected.extend(table.selected());
+            }
+            assert_eq!(selected, order.to_vec());
+        }
+    }
+}
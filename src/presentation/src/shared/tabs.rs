@@ -13,12 +13,12 @@ pub struct TabsState<'a> {
 }
 
 impl<'a> TabsState<'a> {
-    pub fn new(titles: Vec<&'a str>) -> TabsState {
+    pub fn new(titles: Vec<&'a str>) -> TabsState<'a> {
         TabsState { titles, index: 0 }
     }
 
     pub fn new_menu() -> TabsState<'a> {
-        let titles = vec!["Container", "Image", "Volume", "Network"];
+        let titles = vec!["Container", "Image", "Volume", "Network", "System", "Events", "Context"];
         TabsState::new(titles)
     }
 
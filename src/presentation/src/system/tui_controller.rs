@@ -0,0 +1,280 @@
+use crate::shared::{
+    event::{Event, Events},
+    input::TextInput,
+    layout,
+    paragraph::SimpleParagraph,
+    status_bar::StatusBar,
+    table::StatefulTable,
+    tabs::TabsState,
+};
+use crate::{TuiOperationStatus, TuiResult};
+use domain::system::{PruneOptions, PruneTarget};
+
+use termion::event::Key;
+use tui::{backend::Backend, layout::Constraint, Terminal};
+use usecase::{
+    disk_usage::{DiskUsageRecord, DiskUsageUsecase},
+    prune_system::PruneSystemUsecase,
+};
+
+pub struct SystemTuiController<'a> {
+    disk_usage_usecase: &'a DiskUsageUsecase<'a>,
+    prune_usecase: &'a PruneSystemUsecase<'a>,
+}
+
+/// What the keys currently do.
+enum Mode {
+    Browse,
+    Until(TextInput),
+    ConfirmPrune(PruneTarget),
+}
+
+impl<'a> SystemTuiController<'a> {
+    pub fn new(disk_usage_usecase: &'a DiskUsageUsecase<'a>, prune_usecase: &'a PruneSystemUsecase<'a>) -> Self {
+        Self {
+            disk_usage_usecase,
+            prune_usecase,
+        }
+    }
+
+    /// Shows the disk usage of each kind of object. `p` prunes the selected kind after confirming with `y`,
+    /// `a` toggles pruning everything unused rather than only dangling objects, `u` limits pruning to
+    /// objects older than a time and `r` reloads the usage.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        tab: &mut TabsState<'a>,
+        events: &Events,
+    ) -> TuiResult {
+        let mut status_bar = StatusBar::new();
+        let mut mode = Mode::Browse;
+        let mut options = PruneOptions::default();
+        // Results of the prunes since the tab was opened, the latest first.
+        let mut results: Vec<String> = vec![];
+        let mut records = self.load(&mut status_bar).await;
+        // disk usage table
+        let header = vec!["TYPE", "TOTAL", "ACTIVE", "SIZE", "RECLAIMABLE"];
+        let widths = vec![
+            Constraint::Percentage(25),
+            Constraint::Percentage(12),
+            Constraint::Percentage(12),
+            Constraint::Percentage(20),
+            Constraint::Percentage(31),
+        ];
+        let mut usage_table = StatefulTable::new(
+            records_to_table(&records),
+            "Disk usage (p: prune, a: all, u: until, r: reload)",
+            header,
+            widths,
+        );
+        usage_table.next();
+        // prune options and results paragraph
+        let mut paragraph = SimpleParagraph::new("Prune", format_prune_text(&options, &results));
+
+        loop {
+            terminal.draw(|f| {
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_header_and_main(f);
+                let header = areas.0;
+                let main = areas.1;
+                tab.draw(f, header);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_off_bottom_rows(main, 1);
+                let main = areas.0;
+                let status = areas.1;
+                match &mode {
+                    Mode::Until(input) => input.render(f, status),
+                    _ => status_bar.render(f, status),
+                }
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_horizontal_pains(main);
+                let left_pain = areas.0;
+                let right_pain = areas.1;
+                usage_table.render(f, left_pain);
+                paragraph.render(f, right_pain);
+            })?;
+
+            let key = match events.next_async().await? {
+                Event::Input(key) => key,
+                Event::Tick => continue,
+            };
+            if usage_table.is_searching() {
+                usage_table.handle_search_key(key);
+                if !usage_table.is_searching() {
+                    events.enable_exit_key();
+                }
+                continue;
+            }
+            mode = match mode {
+                Mode::Until(mut input) => match key {
+                    Key::Char('\n') => {
+                        events.enable_exit_key();
+                        let until = input.value.trim();
+                        options.until = if until.is_empty() {
+                            None
+                        } else {
+                            Some(until.to_string())
+                        };
+                        paragraph.texts = format_prune_text(&options, &results);
+                        Mode::Browse
+                    }
+                    Key::Esc => {
+                        events.enable_exit_key();
+                        Mode::Browse
+                    }
+                    key => {
+                        input.handle_key(key);
+                        Mode::Until(input)
+                    }
+                },
+                Mode::ConfirmPrune(target) => {
+                    if key == Key::Char('y') {
+                        match self.prune_usecase.prune(target, options.clone()).await {
+                            Ok(report) => {
+                                let result = format!(
+                                    "Removed {} {}, reclaimed {}",
+                                    report.deleted.len(),
+                                    target_name(target).to_lowercase(),
+                                    report.space_reclaimed
+                                );
+                                status_bar.info(result.clone());
+                                results.insert(0, result);
+                            }
+                            Err(e) => status_bar.error(format!("Failed to prune {}: {}", target_name(target), e)),
+                        }
+                        records = self.load(&mut status_bar).await;
+                        usage_table.items = records_to_table(&records);
+                        paragraph.texts = format_prune_text(&options, &results);
+                    } else {
+                        status_bar.clear();
+                    }
+                    Mode::Browse
+                }
+                Mode::Browse => match key {
+                    Key::Char('q') => {
+                        break;
+                    }
+                    Key::Char('/') => {
+                        events.disable_exit_key();
+                        usage_table.start_search();
+                        Mode::Browse
+                    }
+                    Key::Down => {
+                        usage_table.next();
+                        Mode::Browse
+                    }
+                    Key::Up => {
+                        usage_table.previous();
+                        Mode::Browse
+                    }
+                    Key::Char('p') => match usage_table.selected().and_then(|v| records.get(v)) {
+                        Some(record) => {
+                            status_bar.info(prune_prompt(record.target, &options));
+                            Mode::ConfirmPrune(record.target)
+                        }
+                        None => Mode::Browse,
+                    },
+                    Key::Char('a') => {
+                        options.all = !options.all;
+                        paragraph.texts = format_prune_text(&options, &results);
+                        Mode::Browse
+                    }
+                    Key::Char('u') => {
+                        events.disable_exit_key();
+                        Mode::Until(TextInput::new(
+                            "Prune objects created before, e.g. 24h or 2021-01-01 (empty: any time, Esc: cancel)",
+                        ))
+                    }
+                    Key::Char('r') => {
+                        status_bar.clear();
+                        records = self.load(&mut status_bar).await;
+                        usage_table.items = records_to_table(&records);
+                        Mode::Browse
+                    }
+                    Key::Right => {
+                        tab.next();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    Key::Left => {
+                        tab.previous();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    _ => Mode::Browse,
+                },
+            };
+        }
+        Ok(TuiOperationStatus::Quit)
+    }
+
+    async fn load(&self, status_bar: &mut StatusBar) -> Vec<DiskUsageRecord> {
+        match self.disk_usage_usecase.disk_usage().await {
+            Ok(records) => records,
+            Err(e) => {
+                status_bar.error(format!("Failed to get disk usage: {}", e));
+                vec![]
+            }
+        }
+    }
+}
+
+fn target_name(target: PruneTarget) -> &'static str {
+    match target {
+        PruneTarget::Images => "Images",
+        PruneTarget::Containers => "Containers",
+        PruneTarget::Volumes => "Volumes",
+        PruneTarget::BuildCache => "Build cache",
+    }
+}
+
+/// What pruning the kind removes with the options.
+fn prune_scope(target: PruneTarget, all: bool) -> &'static str {
+    match (target, all) {
+        (PruneTarget::Images, false) => "dangling images",
+        (PruneTarget::Images, true) => "images without containers",
+        (PruneTarget::Containers, _) => "stopped containers",
+        (PruneTarget::Volumes, false) => "unused anonymous volumes",
+        (PruneTarget::Volumes, true) => "unused volumes",
+        (PruneTarget::BuildCache, false) => "dangling build cache",
+        (PruneTarget::BuildCache, true) => "unused build cache",
+    }
+}
+
+fn prune_prompt(target: PruneTarget, options: &PruneOptions) -> String {
+    match &options.until {
+        Some(until) => format!(
+            "Remove {} created before {}? (y/n)",
+            prune_scope(target, options.all),
+            until
+        ),
+        None => format!("Remove {}? (y/n)", prune_scope(target, options.all)),
+    }
+}
+
+fn records_to_table(records: &[DiskUsageRecord]) -> Vec<Vec<String>> {
+    records
+        .iter()
+        .map(|r| {
+            let percentage = if r.size.as_u64() > 0 {
+                r.reclaimable.as_u64() as f64 * 100.0 / r.size.as_u64() as f64
+            } else {
+                0.0
+            };
+            vec![
+                target_name(r.target).to_string(),
+                r.total.to_string(),
+                r.active.to_string(),
+                r.size.to_string(),
+                format!("{} ({:.0}%)", r.reclaimable, percentage),
+            ]
+        })
+        .collect()
+}
+
+fn format_prune_text(options: &PruneOptions, results: &[String]) -> Vec<String> {
+    let mut texts = Vec::new();
+    texts.push(format!("all: {}", if options.all { "on" } else { "off" }));
+    texts.push(format!("until: {}", options.until.as_deref().unwrap_or("-")));
+    texts.push("results: ".to_string());
+    results.iter().for_each(|r| texts.push(format!("- {}", r)));
+    texts
+}
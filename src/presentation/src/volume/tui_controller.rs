@@ -0,0 +1,454 @@
+use crate::events::feed::EventFeed;
+use crate::shared::{
+    event::{Event, Events},
+    input::TextInput,
+    layout,
+    loader::{Loader, Next},
+    paragraph::SimpleParagraph,
+    sort::{SortKey, Sorts},
+    spinner,
+    status_bar::StatusBar,
+    table::{StatefulTable, StatelessTable},
+    tabs::TabsState,
+};
+use crate::{TuiOperationStatus, TuiResult};
+use domain::event::EventKind;
+use domain::volume::Volume;
+
+use termion::event::Key;
+use tui::{backend::Backend, layout::Constraint, Terminal};
+use usecase::{
+    create_volume::CreateVolumeUsecase,
+    inspect_volume::{InspectVolumeDto, InspectVolumeUsecase, MountingContainerRecord},
+    list_volumes::ListVolumesUsecase,
+    prune_volumes::PruneVolumesUsecase,
+    remove_volume::RemoveVolumeUsecase,
+};
+
+pub struct VolumeTuiController<'a> {
+    list_usecase: &'a ListVolumesUsecase<'a>,
+    inspect_usecase: &'a InspectVolumeUsecase<'a>,
+    create_usecase: &'a CreateVolumeUsecase<'a>,
+    remove_usecase: &'a RemoveVolumeUsecase<'a>,
+    prune_usecase: &'a PruneVolumesUsecase<'a>,
+    event_feed: &'a EventFeed,
+    sorts: &'a Sorts,
+}
+
+const VOLUMES_TITLE: &str = "Volumes (c: create, d: remove, p: prune)";
+
+/// What the view loads in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Slot {
+    Volumes,
+    Detail,
+    /// Creating, removing or pruning volumes, one at a time.
+    Change,
+}
+
+enum Loaded {
+    Volumes(Result<Vec<Volume>, String>),
+    /// The details of the volume with the name.
+    Detail(String, Result<Box<InspectVolumeDto>, String>),
+    /// What a change did, or why it failed, for the status bar. Events reload the list.
+    Changed(Result<String, String>),
+}
+
+/// What the keys currently do.
+enum Mode {
+    Browse,
+    Create(TextInput),
+    ConfirmRemove(String),
+    ConfirmPrune,
+}
+
+impl<'a> VolumeTuiController<'a> {
+    pub fn new(
+        list_usecase: &'a ListVolumesUsecase<'a>,
+        inspect_usecase: &'a InspectVolumeUsecase<'a>,
+        create_usecase: &'a CreateVolumeUsecase<'a>,
+        remove_usecase: &'a RemoveVolumeUsecase<'a>,
+        prune_usecase: &'a PruneVolumesUsecase<'a>,
+        event_feed: &'a EventFeed,
+        sorts: &'a Sorts,
+    ) -> Self {
+        Self {
+            list_usecase,
+            inspect_usecase,
+            create_usecase,
+            remove_usecase,
+            prune_usecase,
+            event_feed,
+            sorts,
+        }
+    }
+
+    /// Lists volumes with the containers mounting the selected one. `c` creates a volume, `d` removes
+    /// the selected one and `p` prunes the unused ones, the last two after confirming with `y`.
+    pub async fn draw(
+        &self,
+        terminal: &mut Terminal<impl Backend>,
+        tab: &mut TabsState<'a>,
+        events: &Events,
+    ) -> TuiResult {
+        let mut status_bar = StatusBar::new();
+        let mut mode = Mode::Browse;
+        self.event_feed.update();
+        let mut revision = self.event_feed.revision(&EventKind::Volume);
+        let mut loader = Loader::new();
+        self.load_volumes(&mut loader);
+        let mut volumes: Vec<Volume> = vec![];
+        // volume list table
+        let header = vec!["NAME", "DRIVER", "MOUNTPOINT", "SIZE", "REFS"];
+        let widths = vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(10),
+            Constraint::Percentage(40),
+            Constraint::Percentage(12),
+            Constraint::Percentage(8),
+        ];
+        let mut volume_table = StatefulTable::new(vec![], VOLUMES_TITLE, header, widths);
+        volume_table.set_sort(self.sorts.get("Volume"));
+        // volume detail paragraph
+        let mut paragraph = SimpleParagraph::new("Detail", vec!["It shows volume's details here"]);
+        // mounting containers table
+        let header = vec!["CONTAINER", "STATE", "DESTINATION", "RW"];
+        let widths = vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(15),
+            Constraint::Percentage(45),
+            Constraint::Percentage(10),
+        ];
+        let mut container_table = StatelessTable::new(vec![], "Mounted by", header, widths);
+
+        loop {
+            // Reload the list whenever volumes were created, removed, mounted or unmounted.
+            self.event_feed.update();
+            let current = self.event_feed.revision(&EventKind::Volume);
+            if current != revision {
+                revision = current;
+                self.load_volumes(&mut loader);
+            }
+            volume_table.title = spinner::title(VOLUMES_TITLE, loader.is_loading(Slot::Volumes));
+            paragraph.title = spinner::title("Detail", loader.is_loading(Slot::Detail));
+
+            terminal.draw(|f| {
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_header_and_main(f);
+                let header = areas.0;
+                let main = areas.1;
+                tab.draw(f, header);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_off_bottom_rows(main, 1);
+                let main = areas.0;
+                let status = areas.1;
+                match &mode {
+                    Mode::Create(input) => input.render(f, status),
+                    _ => status_bar.render(f, status),
+                }
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_horizontal_pains(main);
+                let left_pain = areas.0;
+                let right_pain = areas.1;
+                volume_table.render(f, left_pain);
+                // TODO: Change it when split assignments are included in Rust's standard functions.
+                let areas = layout::split_into_vertical_pains(right_pain);
+                let detail_up = areas.0;
+                let detail_down = areas.1;
+                paragraph.render(f, detail_up);
+                container_table.render(f, detail_down);
+            })?;
+
+            let key = match loader.next(events).await? {
+                Next::Event(Event::Input(key)) => key,
+                Next::Event(Event::Tick) => continue,
+                Next::Loaded(Loaded::Volumes(Ok(loaded))) => {
+                    let previous = volume_table
+                        .selected()
+                        .and_then(|v| volumes.get(v))
+                        .map(|v| v.name.clone());
+                    volumes = loaded;
+                    volume_table.items = volumes_to_table(&volumes);
+                    volume_table.sort_keys = volumes_to_sort_keys(&volumes);
+                    let selected = previous
+                        .as_ref()
+                        .and_then(|name| volumes.iter().position(|v| v.name == *name));
+                    volume_table.select(selected);
+                    match selected.and_then(|v| volumes.get(v)) {
+                        Some(volume) => self.load_detail(&mut loader, volume),
+                        None if previous.is_some() => {
+                            loader.cancel(Slot::Detail);
+                            paragraph.texts = vec!["It shows volume's details here".to_string()];
+                            container_table.items = vec![];
+                        }
+                        None => {}
+                    }
+                    continue;
+                }
+                Next::Loaded(Loaded::Volumes(Err(e))) => {
+                    status_bar.error(format!("Failed to list volumes: {}", e));
+                    continue;
+                }
+                Next::Loaded(Loaded::Changed(Ok(message))) => {
+                    status_bar.info(message);
+                    continue;
+                }
+                Next::Loaded(Loaded::Changed(Err(message))) => {
+                    status_bar.error(message);
+                    continue;
+                }
+                Next::Loaded(Loaded::Detail(name, detail)) => {
+                    // The list may have been reloaded with another volume at the selected row meanwhile.
+                    let selected = volume_table.selected().and_then(|v| volumes.get(v));
+                    if matches!(selected, Some(v) if v.name == name) {
+                        let detail = gen_detail_text(detail);
+                        paragraph.texts = detail.0;
+                        container_table.items = detail.1;
+                    }
+                    continue;
+                }
+            };
+            if volume_table.is_searching() {
+                if volume_table.handle_search_key(key) {
+                    match volume_table.selected().and_then(|v| volumes.get(v)) {
+                        Some(volume) => self.load_detail(&mut loader, volume),
+                        None => {
+                            loader.cancel(Slot::Detail);
+                            paragraph.texts = vec!["It shows volume's details here".to_string()];
+                            container_table.items = vec![];
+                        }
+                    }
+                }
+                if !volume_table.is_searching() {
+                    events.enable_exit_key();
+                }
+                continue;
+            }
+            mode = match mode {
+                Mode::Create(mut input) => match key {
+                    Key::Char('\n') => {
+                        events.enable_exit_key();
+                        status_bar.info("Creating the volume");
+                        let usecase = self.create_usecase;
+                        loader.load(Slot::Change, async move {
+                            Loaded::Changed(match usecase.create_volume(input.value).await {
+                                Ok(volume) => Ok(format!("Created volume {}", volume.name)),
+                                Err(e) => Err(format!("Failed to create volume: {}", e)),
+                            })
+                        });
+                        Mode::Browse
+                    }
+                    Key::Esc => {
+                        events.enable_exit_key();
+                        status_bar.clear();
+                        Mode::Browse
+                    }
+                    key => {
+                        input.handle_key(key);
+                        Mode::Create(input)
+                    }
+                },
+                Mode::ConfirmRemove(name) => {
+                    if key == Key::Char('y') {
+                        status_bar.info(format!("Removing volume {}", name));
+                        let usecase = self.remove_usecase;
+                        loader.load(Slot::Change, async move {
+                            Loaded::Changed(match usecase.remove_volume(name.as_str()).await {
+                                Ok(()) => Ok(format!("Removed volume {}", name)),
+                                Err(e) => Err(format!("Failed to remove volume {}: {}", name, e)),
+                            })
+                        });
+                    } else {
+                        status_bar.clear();
+                    }
+                    Mode::Browse
+                }
+                Mode::ConfirmPrune => {
+                    if key == Key::Char('y') {
+                        status_bar.info("Pruning volumes");
+                        let usecase = self.prune_usecase;
+                        loader.load(Slot::Change, async move {
+                            Loaded::Changed(match usecase.prune_volumes().await {
+                                Ok(report) => Ok(format!(
+                                    "Removed {} unused volumes, reclaimed {}",
+                                    report.volumes_deleted.len(),
+                                    report.space_reclaimed
+                                )),
+                                Err(e) => Err(format!("Failed to prune volumes: {}", e)),
+                            })
+                        });
+                    } else {
+                        status_bar.clear();
+                    }
+                    Mode::Browse
+                }
+                Mode::Browse => match key {
+                    Key::Char('q') => {
+                        break;
+                    }
+                    Key::Char('/') => {
+                        events.disable_exit_key();
+                        volume_table.start_search();
+                        Mode::Browse
+                    }
+                    Key::Char('o') => {
+                        volume_table.cycle_sort();
+                        self.sorts.set("Volume", volume_table.sort());
+                        Mode::Browse
+                    }
+                    Key::Down | Key::Up => {
+                        if key == Key::Down {
+                            volume_table.next();
+                        } else {
+                            volume_table.previous();
+                        }
+                        match volume_table.selected().and_then(|v| volumes.get(v)) {
+                            Some(volume) => self.load_detail(&mut loader, volume),
+                            None => {
+                                loader.cancel(Slot::Detail);
+                                paragraph.texts = vec!["It shows volume's details here".to_string()];
+                                container_table.items = vec![];
+                            }
+                        }
+                        Mode::Browse
+                    }
+                    Key::Char('c') | Key::Char('d') | Key::Char('p') if loader.is_loading(Slot::Change) => {
+                        status_bar.info("Wait for the running change to finish");
+                        Mode::Browse
+                    }
+                    Key::Char('c') => {
+                        events.disable_exit_key();
+                        Mode::Create(TextInput::new("New volume name (empty: generated, Esc: cancel)"))
+                    }
+                    Key::Char('d') => match volume_table.selected().and_then(|v| volumes.get(v)) {
+                        Some(volume) => {
+                            status_bar.info(format!("Remove volume {}? (y/n)", volume.name));
+                            Mode::ConfirmRemove(volume.name.clone())
+                        }
+                        None => Mode::Browse,
+                    },
+                    Key::Char('p') => {
+                        status_bar.info("Remove every volume no container uses? (y/n)");
+                        Mode::ConfirmPrune
+                    }
+                    Key::Right => {
+                        tab.next();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    Key::Left => {
+                        tab.previous();
+                        return Ok(TuiOperationStatus::Continue);
+                    }
+                    _ => Mode::Browse,
+                },
+            };
+        }
+        Ok(TuiOperationStatus::Quit)
+    }
+
+    fn load_volumes(&self, loader: &mut Loader<'a, Slot, Loaded>) {
+        let usecase = self.list_usecase;
+        loader.load(Slot::Volumes, async move {
+            Loaded::Volumes(usecase.list_volumes().await.map_err(|e| e.to_string()))
+        });
+    }
+
+    /// The usage comes from the list, as inspecting a volume doesn't report it.
+    fn load_detail(&self, loader: &mut Loader<'a, Slot, Loaded>, volume: &Volume) {
+        let usecase = self.inspect_usecase;
+        let name = volume.name.clone();
+        let usage = volume.usage;
+        loader.load(Slot::Detail, async move {
+            let detail = usecase
+                .inspect_volume(name.as_str(), usage)
+                .await
+                .map(Box::new)
+                .map_err(|e| e.to_string());
+            Loaded::Detail(name, detail)
+        });
+    }
+}
+
+fn volumes_to_table(volumes: &[Volume]) -> Vec<Vec<String>> {
+    volumes
+        .iter()
+        .map(|v| {
+            let usage = v.usage.as_ref();
+            vec![
+                v.name.clone(),
+                v.driver.clone(),
+                v.mountpoint.clone(),
+                usage
+                    .and_then(|u| u.size)
+                    .map_or("-".to_string(), |size| size.to_string()),
+                usage.map_or("-".to_string(), |u| u.ref_count.to_string()),
+            ]
+        })
+        .collect()
+}
+
+fn volumes_to_sort_keys(volumes: &[Volume]) -> Vec<Vec<SortKey>> {
+    volumes
+        .iter()
+        .map(|v| {
+            let usage = v.usage.as_ref();
+            vec![
+                SortKey::text(&v.name),
+                SortKey::text(&v.driver),
+                SortKey::text(&v.mountpoint),
+                usage.and_then(|u| u.size).into(),
+                usage.map_or(SortKey::Missing, |u| SortKey::Integer(u.ref_count as i64)),
+            ]
+        })
+        .collect()
+}
+
+fn gen_detail_text(detail: Result<Box<InspectVolumeDto>, String>) -> (Vec<String>, Vec<Vec<String>>) {
+    match detail {
+        Ok(v) => (format_detail_text(&v), format_container_text(v.containers)),
+        Err(e) => (vec![format!("Failed to get volume's details: {}", e)], vec![]),
+    }
+}
+
+fn format_detail_text(detail: &InspectVolumeDto) -> Vec<String> {
+    let mut texts = Vec::new();
+    texts.push(format!("name: {}", detail.name));
+    texts.push(format!("driver: {}", detail.driver));
+    texts.push(format!("scope: {}", detail.scope));
+    texts.push(format!("mountpoint: {}", detail.mountpoint));
+    if let Some(created) = detail.created {
+        texts.push(format!("created: {}", created.format("%Y-%m-%d %H:%M:%S")));
+    }
+    if let Some(size) = detail.size {
+        texts.push(format!("size: {}", size));
+    }
+    if let Some(ref_count) = detail.ref_count {
+        texts.push(format!("ref count: {}", ref_count));
+    }
+
+    texts.push("options: ".to_string());
+    for (k, v) in detail.options.iter() {
+        texts.push(format!("- {}: {}", k, v));
+    }
+
+    texts.push("labels: ".to_string());
+    for (k, v) in detail.labels.iter() {
+        texts.push(format!("- {}: {}", k, v));
+    }
+
+    texts
+}
+
+fn format_container_text(containers: Vec<MountingContainerRecord>) -> Vec<Vec<String>> {
+    containers
+        .into_iter()
+        .map(|c| {
+            vec![
+                c.name,
+                c.state,
+                c.destination,
+                if c.read_write { "rw" } else { "ro" }.to_string(),
+            ]
+        })
+        .collect()
+}
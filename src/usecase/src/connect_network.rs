@@ -0,0 +1,48 @@
+use domain::network::NetworkRepository;
+use std::error::Error;
+
+pub struct ConnectNetworkUsecase<'a> {
+    repository: &'a dyn NetworkRepository,
+}
+
+impl<'a> ConnectNetworkUsecase<'a> {
+    pub fn new(network_repository: &'a dyn NetworkRepository) -> Self {
+        Self {
+            repository: network_repository,
+        }
+    }
+
+    /// Connects the container, running or not, to the network.
+    pub async fn connect(
+        &self,
+        id: impl Into<String>,
+        container: impl Into<String>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.repository.connect(id.into(), container.into()).await?;
+        Ok(())
+    }
+
+    pub async fn disconnect(
+        &self,
+        id: impl Into<String>,
+        container: impl Into<String>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.repository.disconnect(id.into(), container.into(), false).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::webapi::inmem::network_repository::FakeNetworkRepository;
+    use tokio;
+    use usecase::connect_network::ConnectNetworkUsecase;
+
+    #[tokio::test]
+    async fn connecting_to_missing_network() {
+        let network_repository = FakeNetworkRepository::new();
+        let connect_network_usecase = ConnectNetworkUsecase::new(&network_repository);
+        assert!(connect_network_usecase.connect("backend-id", "fake").await.is_ok());
+        assert!(connect_network_usecase.disconnect("missing", "fake").await.is_err());
+    }
+}
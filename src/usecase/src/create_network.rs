@@ -0,0 +1,29 @@
+use domain::network::{CreateNetworkOptions, NetworkRepository};
+use std::error::Error;
+
+pub struct CreateNetworkUsecase<'a> {
+    repository: &'a dyn NetworkRepository,
+}
+
+impl<'a> CreateNetworkUsecase<'a> {
+    pub fn new(network_repository: &'a dyn NetworkRepository) -> Self {
+        Self {
+            repository: network_repository,
+        }
+    }
+
+    /// Creates a bridge network which containers can be connected to later, and returns its ID.
+    pub async fn create_network(&self, name: impl Into<String>) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() {
+            return Err("a network needs a name".into());
+        }
+        let options = CreateNetworkOptions {
+            name,
+            attachable: true,
+            ..CreateNetworkOptions::default()
+        };
+        let id = self.repository.create(options).await?;
+        Ok(id)
+    }
+}
@@ -0,0 +1,24 @@
+use domain::volume::{CreateVolumeOptions, Volume, VolumeRepository};
+use std::error::Error;
+
+pub struct CreateVolumeUsecase<'a> {
+    repository: &'a dyn VolumeRepository,
+}
+
+impl<'a> CreateVolumeUsecase<'a> {
+    pub fn new(volume_repository: &'a dyn VolumeRepository) -> Self {
+        Self {
+            repository: volume_repository,
+        }
+    }
+
+    /// Creates a volume with the local driver. The daemon names it when `name` is empty.
+    pub async fn create_volume(&self, name: impl Into<String>) -> Result<Volume, Box<dyn Error + Send + Sync>> {
+        let options = CreateVolumeOptions {
+            name: name.into().trim().to_string(),
+            ..CreateVolumeOptions::default()
+        };
+        let volume = self.repository.create(options).await?;
+        Ok(volume)
+    }
+}
@@ -0,0 +1,127 @@
+use domain::size::ByteSize;
+use domain::system::{DiskUsage, PruneTarget, SystemRepository};
+use std::error::Error;
+
+pub struct DiskUsageUsecase<'a> {
+    repository: &'a dyn SystemRepository,
+}
+
+/// One line of `docker system df`: the space taken by one kind of object and how much pruning it frees.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiskUsageRecord {
+    pub target: PruneTarget,
+    pub total: usize,
+    pub active: usize,
+    pub size: ByteSize,
+    pub reclaimable: ByteSize,
+}
+
+impl<'a> DiskUsageUsecase<'a> {
+    pub fn new(system_repository: &'a dyn SystemRepository) -> Self {
+        Self {
+            repository: system_repository,
+        }
+    }
+
+    /// Images, containers, volumes and the build cache, in that order.
+    pub async fn disk_usage(&self) -> Result<Vec<DiskUsageRecord>, Box<dyn Error + Send + Sync>> {
+        let usage = self.repository.disk_usage().await?;
+        Ok(summarize(&usage))
+    }
+}
+
+/// Counts like the docker CLI does. Layers shared with other images stay when an unused image is
+/// removed, so only the rest of an image in use is kept off the reclaimable space.
+fn summarize(usage: &DiskUsage) -> Vec<DiskUsageRecord> {
+    let images_in_use: Vec<_> = usage
+        .images
+        .iter()
+        .filter(|i| i.containers.map_or(false, |c| c > 0))
+        .collect();
+    let kept: ByteSize = images_in_use
+        .iter()
+        .map(|i| i.size.saturating_sub(i.shared_size.unwrap_or_default()))
+        .sum();
+    let images = DiskUsageRecord {
+        target: PruneTarget::Images,
+        total: usage.images.len(),
+        active: images_in_use.len(),
+        size: usage.layers_size,
+        reclaimable: usage.layers_size.saturating_sub(kept),
+    };
+
+    let containers = DiskUsageRecord {
+        target: PruneTarget::Containers,
+        total: usage.containers.len(),
+        active: usage.containers.iter().filter(|c| c.running).count(),
+        size: usage.containers.iter().filter_map(|c| c.size_rw).sum(),
+        reclaimable: usage
+            .containers
+            .iter()
+            .filter(|c| !c.running)
+            .filter_map(|c| c.size_rw)
+            .sum(),
+    };
+
+    let volumes = DiskUsageRecord {
+        target: PruneTarget::Volumes,
+        total: usage.volumes.len(),
+        active: usage.volumes.iter().filter(|v| v.ref_count > 0).count(),
+        size: usage.volumes.iter().filter_map(|v| v.size).sum(),
+        reclaimable: usage
+            .volumes
+            .iter()
+            .filter(|v| v.ref_count == 0)
+            .filter_map(|v| v.size)
+            .sum(),
+    };
+
+    let build_cache = DiskUsageRecord {
+        target: PruneTarget::BuildCache,
+        total: usage.build_cache.len(),
+        active: usage.build_cache.iter().filter(|b| b.in_use).count(),
+        size: usage.build_cache.iter().filter(|b| !b.shared).map(|b| b.size).sum(),
+        reclaimable: usage
+            .build_cache
+            .iter()
+            .filter(|b| !b.in_use && !b.shared)
+            .map(|b| b.size)
+            .sum(),
+    };
+
+    vec![images, containers, volumes, build_cache]
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::system::PruneTarget;
+    use infrastructure::webapi::inmem::system_repository::FakeSystemRepository;
+    use tokio;
+    use usecase::disk_usage::DiskUsageUsecase;
+
+    #[tokio::test]
+    async fn summarizing_usage() {
+        let system_repository = FakeSystemRepository::new();
+        let disk_usage_usecase = DiskUsageUsecase::new(&system_repository);
+        let records = disk_usage_usecase.disk_usage().await.unwrap();
+
+        let targets: Vec<_> = records.iter().map(|r| r.target).collect();
+        assert_eq!(
+            targets,
+            vec![
+                PruneTarget::Images,
+                PruneTarget::Containers,
+                PruneTarget::Volumes,
+                PruneTarget::BuildCache
+            ]
+        );
+        let summary: Vec<_> = records
+            .iter()
+            .map(|r| (r.total, r.active, r.size.as_u64(), r.reclaimable.as_u64()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![(2, 1, 1_400, 500), (2, 1, 30, 20), (2, 1, 5_120, 1_024), (1, 1, 300, 0)]
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use domain::exec::{ExecRepository, ExecSession};
+use std::error::Error;
+
+pub struct ExecContainerUsecase<'a> {
+    repository: &'a dyn ExecRepository,
+}
+
+impl<'a> ExecContainerUsecase<'a> {
+    pub fn new(exec_repository: &'a dyn ExecRepository) -> Self {
+        Self {
+            repository: exec_repository,
+        }
+    }
+
+    /// Runs the command in the container with a TTY and returns the attached session.
+    pub async fn exec_container(
+        &self,
+        container_id: impl Into<String>,
+        cmd: Vec<String>,
+    ) -> Result<ExecSession, Box<dyn Error + Send + Sync>> {
+        let exec_id = self.repository.create(container_id.into(), cmd).await?;
+        let session = self.repository.start(exec_id).await?;
+        Ok(session)
+    }
+
+    pub async fn resize(
+        &self,
+        exec_id: impl Into<String>,
+        width: u16,
+        height: u16,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.repository.resize(exec_id.into(), width, height).await
+    }
+}
@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use domain::container::ContainerRepository;
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct InspectContainerUsecase<'a> {
+    repository: &'a dyn ContainerRepository,
+}
+
+impl<'a> InspectContainerUsecase<'a> {
+    pub fn new(container_repository: &'a dyn ContainerRepository) -> Self {
+        Self {
+            repository: container_repository,
+        }
+    }
+
+    pub async fn inspect_container(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<InspectContainerDto, Box<dyn Error + Send + Sync>> {
+        let detail = self.repository.inspect(id.into()).await?;
+
+        let state = detail.state;
+        let config = detail.config;
+        let restart_policy = detail.host_config.restart_policy;
+        let dto = InspectContainerDto {
+            id: detail.id,
+            name: detail.name,
+            image: detail.image,
+            created: detail.created,
+            status: state.status,
+            pid: state.pid,
+            exit_code: state.exit_code,
+            oom_killed: state.oom_killed,
+            error: state.error,
+            started_at: state.started_at,
+            finished_at: state.finished_at,
+            health: state.health.map(|h| HealthDto {
+                status: h.status,
+                failing_streak: h.failing_streak,
+                last_output: h.log.into_iter().last().map(|l| l.output),
+            }),
+            restart_policy: match restart_policy.name.as_str() {
+                "" | "no" => "no".to_string(),
+                "on-failure" => format!("on-failure:{}", restart_policy.maximum_retry_count),
+                name => name.to_string(),
+            },
+            network_mode: detail.host_config.network_mode,
+            privileged: detail.host_config.privileged,
+            hostname: config.hostname,
+            user: config.user,
+            working_dir: config.working_dir,
+            entrypoint: config.entrypoint,
+            cmd: config.cmd,
+            environment_variables: config.env,
+            labels: config.labels,
+            ports: detail.network_settings.ports.iter().map(|p| p.to_string()).collect(),
+            mounts: detail
+                .mounts
+                .into_iter()
+                .map(|m| MountRecord {
+                    mount_type: m.mount_type,
+                    source: m.name.unwrap_or(m.source),
+                    destination: m.destination,
+                    read_write: m.rw,
+                })
+                .collect(),
+            networks: detail
+                .network_settings
+                .networks
+                .into_iter()
+                .map(|(name, e)| NetworkRecord {
+                    name,
+                    ip_address: e.ip_address,
+                    gateway: e.gateway,
+                })
+                .collect(),
+        };
+        Ok(dto)
+    }
+}
+
+pub struct InspectContainerDto {
+    pub id: String,
+    pub name: String,
+    pub image: String,
+    pub created: DateTime<Utc>,
+    pub status: String,
+    pub pid: i64,
+    pub exit_code: i64,
+    pub oom_killed: bool,
+    pub error: String,
+    pub started_at: String,
+    pub finished_at: String,
+    pub health: Option<HealthDto>,
+    pub restart_policy: String,
+    pub network_mode: String,
+    pub privileged: bool,
+    pub hostname: String,
+    pub user: String,
+    pub working_dir: String,
+    pub entrypoint: Vec<String>,
+    pub cmd: Vec<String>,
+    pub environment_variables: Vec<String>,
+    pub labels: HashMap<String, String>,
+    pub ports: Vec<String>,
+    pub mounts: Vec<MountRecord>,
+    pub networks: Vec<NetworkRecord>,
+}
+
+pub struct HealthDto {
+    pub status: String,
+    pub failing_streak: i64,
+    pub last_output: Option<String>,
+}
+
+pub struct MountRecord {
+    pub mount_type: String,
+    pub source: String,
+    pub destination: String,
+    pub read_write: bool,
+}
+
+pub struct NetworkRecord {
+    pub name: String,
+    pub ip_address: String,
+    pub gateway: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::webapi::inmem::container_repository::FakeContainerRepository;
+    use tokio;
+    use usecase::inspect_container::InspectContainerUsecase;
+
+    #[tokio::test]
+    async fn inspecting() {
+        let container_repository = FakeContainerRepository::new();
+        let inspect_container_usecase = InspectContainerUsecase::new(&container_repository);
+        let detail = inspect_container_usecase.inspect_container("fake").await.unwrap();
+        assert_eq!(detail.restart_policy, "on-failure:3");
+        assert_eq!(detail.mounts[0].source, "data");
+    }
+}
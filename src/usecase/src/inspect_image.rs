@@ -1,4 +1,5 @@
 use domain::image::ImageRepository;
+use domain::size::ByteSize;
 use std::collections::HashMap;
 use std::error::Error;
 
@@ -55,5 +56,5 @@ pub struct InspectImageDto {
 pub struct HistoryRecord {
     pub image_id: String,
     pub created_by: String,
-    pub size: i32,
+    pub size: ByteSize,
 }
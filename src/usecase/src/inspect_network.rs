@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use domain::network::NetworkRepository;
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct InspectNetworkUsecase<'a> {
+    repository: &'a dyn NetworkRepository,
+}
+
+impl<'a> InspectNetworkUsecase<'a> {
+    pub fn new(network_repository: &'a dyn NetworkRepository) -> Self {
+        Self {
+            repository: network_repository,
+        }
+    }
+
+    pub async fn inspect_network(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<InspectNetworkDto, Box<dyn Error + Send + Sync>> {
+        let detail = self.repository.inspect(id.into()).await?;
+
+        let mut containers: Vec<AttachedContainerRecord> = detail
+            .containers
+            .into_iter()
+            .map(|(id, endpoint)| AttachedContainerRecord {
+                id,
+                name: endpoint.name,
+                ipv4_address: endpoint.ipv4_address,
+                ipv6_address: endpoint.ipv6_address,
+                mac_address: endpoint.mac_address,
+            })
+            .collect();
+        containers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(InspectNetworkDto {
+            id: detail.id,
+            name: detail.name,
+            driver: detail.driver,
+            scope: detail.scope,
+            created: detail.created,
+            internal: detail.internal,
+            attachable: detail.attachable,
+            ipam_driver: detail.ipam.driver,
+            subnets: detail
+                .ipam
+                .config
+                .into_iter()
+                .map(|c| SubnetRecord {
+                    subnet: c.subnet,
+                    gateway: c.gateway,
+                    ip_range: c.ip_range,
+                })
+                .collect(),
+            containers,
+            options: detail.options,
+            labels: detail.labels,
+        })
+    }
+}
+
+pub struct InspectNetworkDto {
+    pub id: String,
+    pub name: String,
+    pub driver: String,
+    pub scope: String,
+    pub created: Option<DateTime<Utc>>,
+    pub internal: bool,
+    pub attachable: bool,
+    pub ipam_driver: String,
+    pub subnets: Vec<SubnetRecord>,
+    /// Sorted by name.
+    pub containers: Vec<AttachedContainerRecord>,
+    pub options: HashMap<String, String>,
+    pub labels: HashMap<String, String>,
+}
+
+pub struct SubnetRecord {
+    pub subnet: String,
+    pub gateway: String,
+    pub ip_range: String,
+}
+
+pub struct AttachedContainerRecord {
+    pub id: String,
+    pub name: String,
+    pub ipv4_address: String,
+    pub ipv6_address: String,
+    pub mac_address: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::webapi::inmem::network_repository::FakeNetworkRepository;
+    use tokio;
+    use usecase::inspect_network::InspectNetworkUsecase;
+
+    #[tokio::test]
+    async fn listing_attached_containers() {
+        let network_repository = FakeNetworkRepository::new();
+        let inspect_network_usecase = InspectNetworkUsecase::new(&network_repository);
+        let detail = inspect_network_usecase.inspect_network("bridge-id").await.unwrap();
+        assert_eq!(detail.subnets[0].subnet, "172.17.0.0/16");
+        assert_eq!(detail.subnets[0].gateway, "172.17.0.1");
+        assert_eq!(detail.containers.len(), 1);
+        assert_eq!(detail.containers[0].ipv4_address, "172.17.0.2/16");
+    }
+}
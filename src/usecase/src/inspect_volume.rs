@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use domain::container::ContainerRepository;
+use domain::size::ByteSize;
+use domain::volume::{VolumeRepository, VolumeUsage};
+use std::collections::HashMap;
+use std::error::Error;
+
+pub struct InspectVolumeUsecase<'a> {
+    repository: &'a dyn VolumeRepository,
+    container_repository: &'a dyn ContainerRepository,
+}
+
+impl<'a> InspectVolumeUsecase<'a> {
+    pub fn new(volume_repository: &'a dyn VolumeRepository, container_repository: &'a dyn ContainerRepository) -> Self {
+        Self {
+            repository: volume_repository,
+            container_repository,
+        }
+    }
+
+    /// The volume with the containers mounting it and where. Inspecting leaves the usage out, so the one
+    /// listed with the volume is taken instead, if any.
+    pub async fn inspect_volume(
+        &self,
+        name: impl Into<String>,
+        listed_usage: Option<VolumeUsage>,
+    ) -> Result<InspectVolumeDto, Box<dyn Error + Send + Sync>> {
+        let volume = self.repository.inspect(name.into()).await?;
+
+        let mut containers = Vec::new();
+        for summary in self.container_repository.list_by_volume(volume.name.clone()).await? {
+            // The summary doesn't tell where the volume is mounted; a container removed in the meantime is left out.
+            let detail = match self.container_repository.inspect(summary.id.clone()).await {
+                Ok(detail) => detail,
+                Err(_) => continue,
+            };
+            for mount in detail.mounts.iter().filter(|m| m.name.as_ref() == Some(&volume.name)) {
+                containers.push(MountingContainerRecord {
+                    name: summary.name().to_string(),
+                    state: summary.state.clone(),
+                    destination: mount.destination.clone(),
+                    read_write: mount.rw,
+                });
+            }
+        }
+
+        let usage = volume.usage.or(listed_usage);
+        Ok(InspectVolumeDto {
+            name: volume.name,
+            driver: volume.driver,
+            mountpoint: volume.mountpoint,
+            created: volume.created,
+            scope: volume.scope,
+            labels: volume.labels,
+            options: volume.options,
+            size: usage.as_ref().and_then(|u| u.size),
+            ref_count: usage.map(|u| u.ref_count),
+            containers,
+        })
+    }
+}
+
+pub struct InspectVolumeDto {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+    pub created: Option<DateTime<Utc>>,
+    pub scope: String,
+    pub labels: HashMap<String, String>,
+    pub options: HashMap<String, String>,
+    pub size: Option<ByteSize>,
+    pub ref_count: Option<u64>,
+    pub containers: Vec<MountingContainerRecord>,
+}
+
+pub struct MountingContainerRecord {
+    pub name: String,
+    pub state: String,
+    pub destination: String,
+    pub read_write: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::volume::VolumeRepository;
+    use infrastructure::webapi::inmem::container_repository::FakeContainerRepository;
+    use infrastructure::webapi::inmem::volume_repository::FakeVolumeRepository;
+    use tokio;
+    use usecase::inspect_volume::InspectVolumeUsecase;
+
+    #[tokio::test]
+    async fn finding_mounting_containers() {
+        let volume_repository = FakeVolumeRepository::new();
+        let container_repository = FakeContainerRepository::new();
+        let inspect_volume_usecase = InspectVolumeUsecase::new(&volume_repository, &container_repository);
+
+        let listed = volume_repository.list().await.unwrap().remove(0).usage;
+        let detail = inspect_volume_usecase.inspect_volume("data", listed).await.unwrap();
+        assert_eq!(detail.ref_count, Some(1));
+        assert_eq!(detail.containers.len(), 1);
+        assert_eq!(detail.containers[0].name, "fake");
+        assert_eq!(detail.containers[0].destination, "/data");
+
+        let detail = inspect_volume_usecase.inspect_volume("cache", None).await.unwrap();
+        assert!(detail.containers.is_empty());
+        assert_eq!(detail.ref_count, None);
+    }
+}
@@ -1,3 +1,27 @@
+pub mod connect_network;
+pub mod create_network;
+pub mod create_volume;
+pub mod disk_usage;
+pub mod exec_container;
+pub mod inspect_container;
 pub mod inspect_image;
+pub mod inspect_network;
+pub mod inspect_volume;
 pub mod list_containers;
+pub mod list_contexts;
 pub mod list_image;
+pub mod list_networks;
+pub mod list_volumes;
+pub mod monitor_stats;
+pub mod operate_container;
+pub mod prune_networks;
+pub mod prune_system;
+pub mod prune_volumes;
+pub mod pull_image;
+pub mod read_logs;
+pub mod refresh_cache;
+pub mod remove_image;
+pub mod remove_network;
+pub mod remove_volume;
+pub mod tag_image;
+pub mod watch_events;
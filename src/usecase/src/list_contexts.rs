@@ -0,0 +1,37 @@
+use domain::context::{Context, ContextRepository};
+use std::error::Error;
+
+pub struct ListContextsUsecase<'a> {
+    repository: &'a dyn ContextRepository,
+}
+
+impl<'a> ListContextsUsecase<'a> {
+    pub fn new(context_repository: &'a dyn ContextRepository) -> Self {
+        Self {
+            repository: context_repository,
+        }
+    }
+
+    pub async fn list_contexts(&self) -> Result<Vec<Context>, Box<dyn Error + Send + Sync>> {
+        let contexts = self.repository.list().await?;
+        Ok(contexts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::context::DEFAULT_CONTEXT;
+    use infrastructure::config::context_repository::FileContextRepository;
+    use tokio;
+    use usecase::list_contexts::ListContextsUsecase;
+
+    #[tokio::test]
+    async fn listing_without_stored_contexts() {
+        let context_repository = FileContextRepository::new("/nonexistent", "unix:///var/run/docker.sock");
+        let list_contexts_usecase = ListContextsUsecase::new(&context_repository);
+        let contexts = list_contexts_usecase.list_contexts().await.unwrap();
+        assert_eq!(contexts.len(), 1);
+        assert_eq!(contexts[0].name, DEFAULT_CONTEXT);
+        assert!(contexts[0].current);
+    }
+}
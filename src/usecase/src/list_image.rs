@@ -12,8 +12,12 @@ impl<'a> ListImageUsecase<'a> {
         }
     }
 
-    pub async fn list_image(&self) -> Result<Vec<ImageSummary>, Box<dyn Error + Send + Sync>> {
-        let images = self.repository.list().await?;
+    /// Dangling images are left out unless `dangling` is set.
+    pub async fn list_image(&self, dangling: bool) -> Result<Vec<ImageSummary>, Box<dyn Error + Send + Sync>> {
+        let mut images = self.repository.list().await?;
+        if !dangling {
+            images.retain(|i| !i.is_dangling());
+        }
         Ok(images)
     }
 }
@@ -28,7 +32,10 @@ mod tests {
     async fn listing() {
         let image_repository = FakeImageRepository::new();
         let list_image_usecase = ListImageUsecase::new(&image_repository);
-        let image_summaries = list_image_usecase.list_image().await;
-        assert!(image_summaries.is_ok());
+        let image_summaries = list_image_usecase.list_image(false).await.unwrap();
+        assert_eq!(image_summaries.len(), 2);
+        assert!(image_summaries.iter().all(|i| !i.is_dangling()));
+        let image_summaries = list_image_usecase.list_image(true).await.unwrap();
+        assert_eq!(image_summaries.len(), 3);
     }
 }
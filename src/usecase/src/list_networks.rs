@@ -0,0 +1,21 @@
+use domain::network::{Network, NetworkRepository};
+use std::error::Error;
+
+pub struct ListNetworksUsecase<'a> {
+    repository: &'a dyn NetworkRepository,
+}
+
+impl<'a> ListNetworksUsecase<'a> {
+    pub fn new(network_repository: &'a dyn NetworkRepository) -> Self {
+        Self {
+            repository: network_repository,
+        }
+    }
+
+    /// Networks sorted by name, as the daemon returns them in no particular order.
+    pub async fn list_networks(&self) -> Result<Vec<Network>, Box<dyn Error + Send + Sync>> {
+        let mut networks = self.repository.list().await?;
+        networks.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(networks)
+    }
+}
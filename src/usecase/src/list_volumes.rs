@@ -0,0 +1,37 @@
+use domain::volume::{Volume, VolumeRepository};
+use std::error::Error;
+
+pub struct ListVolumesUsecase<'a> {
+    repository: &'a dyn VolumeRepository,
+}
+
+impl<'a> ListVolumesUsecase<'a> {
+    pub fn new(volume_repository: &'a dyn VolumeRepository) -> Self {
+        Self {
+            repository: volume_repository,
+        }
+    }
+
+    /// Volumes sorted by name, as the daemon returns them in no particular order.
+    pub async fn list_volumes(&self) -> Result<Vec<Volume>, Box<dyn Error + Send + Sync>> {
+        let mut volumes = self.repository.list().await?;
+        volumes.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(volumes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::webapi::inmem::volume_repository::FakeVolumeRepository;
+    use tokio;
+    use usecase::list_volumes::ListVolumesUsecase;
+
+    #[tokio::test]
+    async fn listing_by_name() {
+        let volume_repository = FakeVolumeRepository::new();
+        let list_volumes_usecase = ListVolumesUsecase::new(&volume_repository);
+        let volumes = list_volumes_usecase.list_volumes().await.unwrap();
+        let names: Vec<_> = volumes.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["cache", "data"]);
+    }
+}
@@ -0,0 +1,102 @@
+use domain::size::ByteSize;
+use domain::stats::{StatsRepository, StatsSample};
+use futures_util::stream::{Stream, StreamExt};
+use std::error::Error;
+use std::pin::Pin;
+
+pub type ResourceUsageStream =
+    Pin<Box<dyn Stream<Item = Result<ResourceUsageDto, Box<dyn Error + Send + Sync>>> + Send>>;
+
+pub struct MonitorStatsUsecase<'a> {
+    repository: &'a dyn StatsRepository,
+}
+
+impl<'a> MonitorStatsUsecase<'a> {
+    pub fn new(stats_repository: &'a dyn StatsRepository) -> Self {
+        Self {
+            repository: stats_repository,
+        }
+    }
+
+    pub async fn monitor_stats(
+        &self,
+        id: impl Into<String>,
+    ) -> Result<ResourceUsageStream, Box<dyn Error + Send + Sync>> {
+        let samples = self.repository.stats(id.into()).await?;
+
+        let mut previous: Option<StatsSample> = None;
+        let usages = samples.map(move |sample| {
+            let sample = sample?;
+            let usage = to_resource_usage(&sample, previous.as_ref());
+            previous = Some(sample);
+            Ok(usage)
+        });
+        Ok(Box::pin(usages))
+    }
+}
+
+/// Computes the usage of a sample, with I/O rates relative to the previous sample when there is one.
+fn to_resource_usage(sample: &StatsSample, previous: Option<&StatsSample>) -> ResourceUsageDto {
+    let rate = |current: u64, before: fn(&StatsSample) -> u64| match previous {
+        Some(previous) => {
+            let elapsed = (sample.read - previous.read).num_milliseconds();
+            if elapsed <= 0 {
+                return 0.0;
+            }
+            current.saturating_sub(before(previous)) as f64 * 1000.0 / elapsed as f64
+        }
+        None => 0.0,
+    };
+
+    ResourceUsageDto {
+        cpu_percent: sample.cpu_percent(),
+        memory_used: ByteSize(sample.memory_used()),
+        memory_limit: ByteSize(sample.memory_limit),
+        memory_percent: sample.memory_percent(),
+        network_rx_rate: rate(sample.network_rx_bytes, |s| s.network_rx_bytes),
+        network_tx_rate: rate(sample.network_tx_bytes, |s| s.network_tx_bytes),
+        block_read_rate: rate(sample.block_read_bytes, |s| s.block_read_bytes),
+        block_write_rate: rate(sample.block_write_bytes, |s| s.block_write_bytes),
+        pids: sample.pids,
+    }
+}
+
+pub struct ResourceUsageDto {
+    pub cpu_percent: f64,
+    pub memory_used: ByteSize,
+    pub memory_limit: ByteSize,
+    pub memory_percent: f64,
+    /// Bytes per second.
+    pub network_rx_rate: f64,
+    pub network_tx_rate: f64,
+    pub block_read_rate: f64,
+    pub block_write_rate: f64,
+    pub pids: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::stream::StreamExt;
+    use infrastructure::webapi::inmem::stats_repository::FakeStatsRepository;
+    use tokio;
+    use usecase::monitor_stats::MonitorStatsUsecase;
+
+    #[tokio::test]
+    async fn computing_usage_between_samples() {
+        let stats_repository = FakeStatsRepository::new();
+        let monitor_stats_usecase = MonitorStatsUsecase::new(&stats_repository);
+        let usages: Vec<_> = monitor_stats_usecase
+            .monitor_stats("fake")
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        let first = usages[0].as_ref().unwrap();
+        assert_eq!(first.network_rx_rate, 0.0);
+        let second = usages[1].as_ref().unwrap();
+        assert_eq!(second.cpu_percent, 50.0);
+        assert_eq!(second.memory_used.as_u64(), 768);
+        assert_eq!(second.network_rx_rate, 2048.0);
+    }
+}
@@ -0,0 +1,84 @@
+use domain::container::{Container, ContainerRepository};
+use domain::error::ErrorKind;
+use std::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ContainerAction {
+    Start,
+    Stop,
+    Restart,
+    Kill,
+    Pause,
+    Unpause,
+}
+
+impl ContainerAction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ContainerAction::Start => "start",
+            ContainerAction::Stop => "stop",
+            ContainerAction::Restart => "restart",
+            ContainerAction::Kill => "kill",
+            ContainerAction::Pause => "pause",
+            ContainerAction::Unpause => "unpause",
+        }
+    }
+}
+
+pub struct OperateContainerUsecase<'a> {
+    repository: &'a dyn ContainerRepository,
+}
+
+impl<'a> OperateContainerUsecase<'a> {
+    pub fn new(container_repository: &'a dyn ContainerRepository) -> Self {
+        Self {
+            repository: container_repository,
+        }
+    }
+
+    /// Runs the action on the container and returns it inspected again, or `None` when it no longer
+    /// exists afterwards, e.g. when it was started with `--rm` and stopped.
+    pub async fn operate_container(
+        &self,
+        id: impl Into<String>,
+        action: ContainerAction,
+    ) -> Result<Option<Container>, Box<dyn Error + Send + Sync>> {
+        let id = id.into();
+        match action {
+            ContainerAction::Start => self.repository.start(id.clone()).await?,
+            ContainerAction::Stop => self.repository.stop(id.clone()).await?,
+            ContainerAction::Restart => self.repository.restart(id.clone()).await?,
+            ContainerAction::Kill => self.repository.kill(id.clone()).await?,
+            ContainerAction::Pause => self.repository.pause(id.clone()).await?,
+            ContainerAction::Unpause => self.repository.unpause(id.clone()).await?,
+        }
+
+        match self.repository.inspect(id).await {
+            Ok(container) => Ok(Some(container)),
+            Err(e) if ErrorKind::of(e.as_ref()) == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::webapi::inmem::container_repository::FakeContainerRepository;
+    use tokio;
+    use usecase::operate_container::{ContainerAction, OperateContainerUsecase};
+
+    #[tokio::test]
+    async fn refreshing_after_action() {
+        let container_repository = FakeContainerRepository::new();
+        let operate_container_usecase = OperateContainerUsecase::new(&container_repository);
+        let usecase = &operate_container_usecase;
+        let state = |action| async move {
+            let container = usecase.operate_container("fake", action).await.unwrap();
+            container.map(|c| c.state.status)
+        };
+        assert_eq!(state(ContainerAction::Stop).await, Some("exited".to_string()));
+        assert_eq!(state(ContainerAction::Start).await, Some("running".to_string()));
+        assert_eq!(state(ContainerAction::Pause).await, Some("paused".to_string()));
+        assert_eq!(state(ContainerAction::Unpause).await, Some("running".to_string()));
+    }
+}
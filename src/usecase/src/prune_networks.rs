@@ -0,0 +1,20 @@
+use domain::network::{NetworkPruneReport, NetworkRepository};
+use std::error::Error;
+
+pub struct PruneNetworksUsecase<'a> {
+    repository: &'a dyn NetworkRepository,
+}
+
+impl<'a> PruneNetworksUsecase<'a> {
+    pub fn new(network_repository: &'a dyn NetworkRepository) -> Self {
+        Self {
+            repository: network_repository,
+        }
+    }
+
+    /// Removes every network which no container is connected to.
+    pub async fn prune_networks(&self) -> Result<NetworkPruneReport, Box<dyn Error + Send + Sync>> {
+        let report = self.repository.prune().await?;
+        Ok(report)
+    }
+}
@@ -0,0 +1,26 @@
+use domain::system::{PruneOptions, PruneReport, PruneTarget, SystemRepository};
+use std::error::Error;
+
+pub struct PruneSystemUsecase<'a> {
+    repository: &'a dyn SystemRepository,
+}
+
+impl<'a> PruneSystemUsecase<'a> {
+    pub fn new(system_repository: &'a dyn SystemRepository) -> Self {
+        Self {
+            repository: system_repository,
+        }
+    }
+
+    /// Removes the unused objects of one kind and reports how much space it freed.
+    pub async fn prune(
+        &self,
+        target: PruneTarget,
+        options: PruneOptions,
+    ) -> Result<PruneReport, Box<dyn Error + Send + Sync>> {
+        let until = options.until.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+        let options = PruneOptions { until, ..options };
+        let report = self.repository.prune(target, options).await?;
+        Ok(report)
+    }
+}
@@ -0,0 +1,20 @@
+use domain::volume::{VolumePruneReport, VolumeRepository};
+use std::error::Error;
+
+pub struct PruneVolumesUsecase<'a> {
+    repository: &'a dyn VolumeRepository,
+}
+
+impl<'a> PruneVolumesUsecase<'a> {
+    pub fn new(volume_repository: &'a dyn VolumeRepository) -> Self {
+        Self {
+            repository: volume_repository,
+        }
+    }
+
+    /// Removes every volume which no container uses.
+    pub async fn prune_volumes(&self) -> Result<VolumePruneReport, Box<dyn Error + Send + Sync>> {
+        let report = self.repository.prune().await?;
+        Ok(report)
+    }
+}
@@ -0,0 +1,126 @@
+use domain::image::{ImageRepository, PullProgress, PullStream};
+use std::error::Error;
+
+pub struct PullImageUsecase<'a> {
+    repository: &'a dyn ImageRepository,
+}
+
+impl<'a> PullImageUsecase<'a> {
+    pub fn new(image_repository: &'a dyn ImageRepository) -> Self {
+        Self {
+            repository: image_repository,
+        }
+    }
+
+    /// Pulls `repo:tag` or `repo@digest`, defaulting to the `latest` tag like `docker pull` does.
+    pub async fn pull_image(&self, reference: impl Into<String>) -> Result<PullStream, Box<dyn Error + Send + Sync>> {
+        let reference = normalize(&reference.into())?;
+        let progress = self.repository.pull(reference).await?;
+        Ok(progress)
+    }
+}
+
+fn normalize(reference: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let reference = reference.trim();
+    if reference.is_empty() || reference.contains(char::is_whitespace) {
+        return Err(format!("invalid reference format: {:?}", reference).into());
+    }
+    // A colon in the last path component is a tag, one before it may be a registry's port.
+    let name = reference.rsplit('/').next().unwrap_or_default();
+    if reference.contains('@') || name.contains(':') {
+        Ok(reference.to_string())
+    } else {
+        Ok(format!("{}:latest", reference))
+    }
+}
+
+/// The progress of a pull so far, folded from its messages for display.
+#[derive(Default)]
+pub struct PullStatus {
+    /// In the order the daemon first mentioned them.
+    pub layers: Vec<LayerProgress>,
+    /// Messages about the whole image, e.g. the digest.
+    pub messages: Vec<String>,
+}
+
+pub struct LayerProgress {
+    pub id: String,
+    pub status: String,
+    pub current: Option<u64>,
+    pub total: Option<u64>,
+}
+
+impl LayerProgress {
+    /// How far the current step of the layer is, from 0 to 1.
+    pub fn ratio(&self) -> f64 {
+        match (self.current, self.total) {
+            (Some(current), Some(total)) if total > 0 => (current as f64 / total as f64).min(1.0),
+            _ if self.is_done() => 1.0,
+            _ => 0.0,
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        matches!(self.status.as_str(), "Pull complete" | "Already exists")
+    }
+}
+
+impl PullStatus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn apply(&mut self, progress: PullProgress) {
+        let id = match progress.id {
+            Some(id) if !progress.status.starts_with("Pulling from") => id,
+            _ => {
+                self.messages.push(progress.status);
+                return;
+            }
+        };
+        match self.layers.iter_mut().find(|l| l.id == id) {
+            Some(layer) => {
+                layer.status = progress.status;
+                layer.current = progress.current;
+                layer.total = progress.total;
+            }
+            None => self.layers.push(LayerProgress {
+                id,
+                status: progress.status,
+                current: progress.current,
+                total: progress.total,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::stream::StreamExt;
+    use infrastructure::webapi::inmem::image_repository::FakeImageRepository;
+    use tokio;
+    use usecase::pull_image::{PullImageUsecase, PullStatus};
+
+    #[tokio::test]
+    async fn following_layers() {
+        let image_repository = FakeImageRepository::new();
+        let pull_image_usecase = PullImageUsecase::new(&image_repository);
+        let mut progress = pull_image_usecase.pull_image("busybox").await.unwrap();
+
+        let mut status = PullStatus::new();
+        status.apply(progress.next().await.unwrap().unwrap());
+        status.apply(progress.next().await.unwrap().unwrap());
+        status.apply(progress.next().await.unwrap().unwrap());
+        assert_eq!(status.layers.len(), 1);
+        assert_eq!(status.layers[0].ratio(), 0.25);
+        while let Some(item) = progress.next().await {
+            status.apply(item.unwrap());
+        }
+        assert!(status.layers[0].is_done());
+        assert_eq!(status.messages.len(), 2);
+
+        let mut progress = pull_image_usecase.pull_image("missing:1.0").await.unwrap();
+        assert!(progress.next().await.unwrap().is_err());
+        assert!(pull_image_usecase.pull_image(" ").await.is_err());
+    }
+}
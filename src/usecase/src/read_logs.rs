@@ -0,0 +1,23 @@
+use domain::log::{LogOptions, LogRepository, LogStream};
+use std::error::Error;
+
+pub struct ReadLogsUsecase<'a> {
+    repository: &'a dyn LogRepository,
+}
+
+impl<'a> ReadLogsUsecase<'a> {
+    pub fn new(log_repository: &'a dyn LogRepository) -> Self {
+        Self {
+            repository: log_repository,
+        }
+    }
+
+    pub async fn read_logs(
+        &self,
+        id: impl Into<String>,
+        options: LogOptions,
+    ) -> Result<LogStream, Box<dyn Error + Send + Sync>> {
+        let logs = self.repository.logs(id.into(), options).await?;
+        Ok(logs)
+    }
+}
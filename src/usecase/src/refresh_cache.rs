@@ -0,0 +1,28 @@
+use domain::cache::Cache;
+use domain::event::SystemEvent;
+
+pub struct RefreshCacheUsecase<'a> {
+    cache: &'a dyn Cache,
+}
+
+impl<'a> RefreshCacheUsecase<'a> {
+    pub fn new(cache: &'a dyn Cache) -> Self {
+        Self { cache }
+    }
+
+    /// Forgets the objects the events are about. Tag events carry the reference as well, whose image
+    /// it may have been moved away from.
+    pub fn forget(&self, events: &[SystemEvent]) {
+        for event in events {
+            self.cache.invalidate(&event.actor_id);
+            if let Some(name) = event.attributes.get("name") {
+                self.cache.invalidate(name);
+            }
+        }
+    }
+
+    /// Forgets everything, so the next requests reach the daemon.
+    pub fn refresh(&self) {
+        self.cache.clear();
+    }
+}
@@ -0,0 +1,26 @@
+use domain::image::{ImageRemoveReport, ImageRepository};
+use std::error::Error;
+
+pub struct RemoveImageUsecase<'a> {
+    repository: &'a dyn ImageRepository,
+}
+
+impl<'a> RemoveImageUsecase<'a> {
+    pub fn new(image_repository: &'a dyn ImageRepository) -> Self {
+        Self {
+            repository: image_repository,
+        }
+    }
+
+    /// Without `force` it fails with a conflict while containers use the image or it has several tags.
+    /// `no_prune` keeps the parent images which would be left untagged.
+    pub async fn remove_image(
+        &self,
+        id: impl Into<String>,
+        force: bool,
+        no_prune: bool,
+    ) -> Result<ImageRemoveReport, Box<dyn Error + Send + Sync>> {
+        let report = self.repository.remove(id.into(), force, no_prune).await?;
+        Ok(report)
+    }
+}
@@ -0,0 +1,20 @@
+use domain::network::NetworkRepository;
+use std::error::Error;
+
+pub struct RemoveNetworkUsecase<'a> {
+    repository: &'a dyn NetworkRepository,
+}
+
+impl<'a> RemoveNetworkUsecase<'a> {
+    pub fn new(network_repository: &'a dyn NetworkRepository) -> Self {
+        Self {
+            repository: network_repository,
+        }
+    }
+
+    /// Fails while containers are connected and for the predefined `bridge`, `host` and `none` networks.
+    pub async fn remove_network(&self, id: impl Into<String>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.repository.remove(id.into()).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use domain::volume::VolumeRepository;
+use std::error::Error;
+
+pub struct RemoveVolumeUsecase<'a> {
+    repository: &'a dyn VolumeRepository,
+}
+
+impl<'a> RemoveVolumeUsecase<'a> {
+    pub fn new(volume_repository: &'a dyn VolumeRepository) -> Self {
+        Self {
+            repository: volume_repository,
+        }
+    }
+
+    /// Fails with a conflict while a container, even a stopped one, still uses the volume.
+    pub async fn remove_volume(&self, name: impl Into<String>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.repository.remove(name.into(), false).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use domain::image::ImageRepository;
+use std::error::Error;
+
+pub struct TagImageUsecase<'a> {
+    repository: &'a dyn ImageRepository,
+}
+
+impl<'a> TagImageUsecase<'a> {
+    pub fn new(image_repository: &'a dyn ImageRepository) -> Self {
+        Self {
+            repository: image_repository,
+        }
+    }
+
+    /// Adds `repo:tag` to the image, tagging it `latest` when the reference has no tag.
+    pub async fn tag(&self, id: impl Into<String>, reference: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (repo, tag) = split_reference(reference)?;
+        self.repository.tag(id.into(), repo, tag).await?;
+        Ok(())
+    }
+
+    /// Removes one tag of an image, which deletes the image as well when it is its last tag.
+    pub async fn untag(&self, reference: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (repo, tag) = split_reference(reference)?;
+        self.repository
+            .remove(format!("{}:{}", repo, tag), false, false)
+            .await?;
+        Ok(())
+    }
+}
+
+fn split_reference(reference: &str) -> Result<(String, String), Box<dyn Error + Send + Sync>> {
+    let reference = reference.trim();
+    if reference.is_empty() || reference.contains(char::is_whitespace) || reference.contains('@') {
+        return Err(format!("invalid tag: {:?}", reference).into());
+    }
+    // A colon in the last path component is a tag, one before it may be a registry's port.
+    let name_start = reference.rfind('/').map_or(0, |i| i + 1);
+    match reference[name_start..].rfind(':') {
+        Some(i) => {
+            let (repo, tag) = reference.split_at(name_start + i);
+            let tag = &tag[1..];
+            if repo.is_empty() || tag.is_empty() {
+                return Err(format!("invalid tag: {:?}", reference).into());
+            }
+            Ok((repo.to_string(), tag.to_string()))
+        }
+        None => Ok((reference.to_string(), "latest".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use infrastructure::webapi::inmem::image_repository::FakeImageRepository;
+    use tokio;
+    use usecase::remove_image::RemoveImageUsecase;
+    use usecase::tag_image::TagImageUsecase;
+
+    #[tokio::test]
+    async fn tagging_and_removing() {
+        let image_repository = FakeImageRepository::new();
+        let tag_image_usecase = TagImageUsecase::new(&image_repository);
+        assert!(tag_image_usecase.tag("abc", "localhost:5000/app").await.is_ok());
+        assert!(tag_image_usecase.tag("abc", "app:").await.is_err());
+        assert!(tag_image_usecase.untag("busybox").await.is_err());
+
+        let remove_image_usecase = RemoveImageUsecase::new(&image_repository);
+        assert!(remove_image_usecase
+            .remove_image("busybox:latest", false, false)
+            .await
+            .is_err());
+        let report = remove_image_usecase
+            .remove_image("busybox:latest", true, false)
+            .await
+            .unwrap();
+        assert_eq!(report.untagged, vec!["busybox:latest".to_string()]);
+    }
+}
@@ -0,0 +1,49 @@
+use domain::event::{EventRepository, EventStream};
+use std::error::Error;
+
+pub struct WatchEventsUsecase<'a> {
+    repository: &'a dyn EventRepository,
+}
+
+impl<'a> WatchEventsUsecase<'a> {
+    pub fn new(event_repository: &'a dyn EventRepository) -> Self {
+        Self {
+            repository: event_repository,
+        }
+    }
+
+    /// Follows the daemon's events from now on until the stream is dropped.
+    pub async fn watch_events(&self) -> Result<EventStream, Box<dyn Error + Send + Sync>> {
+        let events = self.repository.events(None).await?;
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::event::EventKind;
+    use futures_util::stream::StreamExt;
+    use infrastructure::webapi::inmem::event_repository::FakeEventRepository;
+    use tokio;
+    use usecase::watch_events::WatchEventsUsecase;
+
+    #[tokio::test]
+    async fn telling_events_which_alter_listings() {
+        let event_repository = FakeEventRepository::new();
+        let watch_events_usecase = WatchEventsUsecase::new(&event_repository);
+        let events: Vec<_> = watch_events_usecase
+            .watch_events()
+            .await
+            .unwrap()
+            .map(|event| event.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[0].actor_name(), "web");
+        assert!(events[0].alters_listing());
+        assert_eq!(events[1].verb(), "exec_start");
+        assert!(!events[1].alters_listing());
+        assert_eq!(events[3].kind, EventKind::Image);
+    }
+}